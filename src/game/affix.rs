@@ -0,0 +1,457 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::game::item::{EquipmentTrait, ItemCategory, ItemTrait};
+use crate::game::loadout::EquipmentSlot;
+use crate::game::modifier::StatModifier;
+use crate::game::rng::Rng;
+use crate::game::stat::{Stat, StatField};
+
+/// How rare, and how strong, a rolled item is.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl Rarity {
+    pub const ALL: [Rarity; 5] = [
+        Rarity::Common,
+        Rarity::Uncommon,
+        Rarity::Rare,
+        Rarity::Epic,
+        Rarity::Legendary,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Rarity::Common => "common",
+            Rarity::Uncommon => "uncommon",
+            Rarity::Rare => "rare",
+            Rarity::Epic => "epic",
+            Rarity::Legendary => "legendary",
+        }
+    }
+
+    /// Number of affixes rolled, at most one prefix and one suffix.
+    pub fn affix_count(self) -> usize {
+        match self {
+            Rarity::Common => 0,
+            Rarity::Uncommon => 1,
+            Rarity::Rare | Rarity::Epic | Rarity::Legendary => 2,
+        }
+    }
+
+    /// Factor applied to the rolled affix values.
+    pub fn power(self) -> f32 {
+        match self {
+            Rarity::Common | Rarity::Uncommon => 1.0,
+            Rarity::Rare => 1.25,
+            Rarity::Epic => 1.5,
+            Rarity::Legendary => 2.0,
+        }
+    }
+
+    /// Relative chance of the tier in `Rarity::roll`.
+    pub fn weight(self) -> u32 {
+        match self {
+            Rarity::Common => 60,
+            Rarity::Uncommon => 25,
+            Rarity::Rare => 10,
+            Rarity::Epic => 4,
+            Rarity::Legendary => 1,
+        }
+    }
+
+    /// Pick a tier following the `weight` of each one.
+    pub fn roll<R: Rng + ?Sized>(rng: &mut R) -> Rarity {
+        let total: u32 = Rarity::ALL.iter().map(|rarity| rarity.weight()).sum();
+        let mut roll = rng.below(total as u64) as u32;
+        for rarity in Rarity::ALL {
+            if roll < rarity.weight() {
+                return rarity;
+            }
+            roll -= rarity.weight();
+        }
+        Rarity::Common
+    }
+}
+
+impl fmt::Display for Rarity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Where an affix goes in the item name.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum AffixKind {
+    /// Before the base name, as in "Sturdy Helmet".
+    Prefix,
+    /// After the base name, as in "Helmet of the Bear".
+    Suffix,
+}
+
+/// A bonus to one `StatField` that can be rolled onto equipment.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Affix {
+    pub name: &'static str,
+    pub kind: AffixKind,
+    pub field: StatField,
+    pub min: i32,
+    pub max: i32,
+}
+
+impl Affix {
+    /// # Panics
+    /// Panics if `min` is greater than `max`.
+    pub fn prefix(name: &'static str, field: StatField, min: i32, max: i32) -> Self {
+        Self::new(name, AffixKind::Prefix, field, min, max)
+    }
+
+    /// # Panics
+    /// Panics if `min` is greater than `max`.
+    pub fn suffix(name: &'static str, field: StatField, min: i32, max: i32) -> Self {
+        Self::new(name, AffixKind::Suffix, field, min, max)
+    }
+
+    fn new(name: &'static str, kind: AffixKind, field: StatField, min: i32, max: i32) -> Self {
+        assert!(
+            min <= max,
+            "affix `{}` has min {} above max {}",
+            name,
+            min,
+            max
+        );
+        Self {
+            name,
+            kind,
+            field,
+            min,
+            max,
+        }
+    }
+
+    /// Roll a value in `min..=max`, scaled by the `Rarity::power`.
+    pub fn roll<R: Rng + ?Sized>(&self, rarity: Rarity, rng: &mut R) -> RolledAffix {
+        let value = rng.range(self.min, self.max);
+        let mut stat = Stat::default();
+        stat.set(self.field, (value as f32 * rarity.power()).round() as i32);
+        RolledAffix {
+            name: self.name,
+            kind: self.kind,
+            stat,
+        }
+    }
+}
+
+/// An `Affix` with its value rolled.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RolledAffix {
+    pub name: &'static str,
+    pub kind: AffixKind,
+    pub stat: Stat,
+}
+
+/// The affixes an `AffixPool` picks from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AffixPool {
+    prefixes: Vec<Affix>,
+    suffixes: Vec<Affix>,
+}
+
+impl AffixPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A prefix and a suffix for every `StatField`.
+    pub fn standard() -> Self {
+        let mut pool = Self::new();
+        pool.add(Affix::prefix("Hale", StatField::Health, 5, 10));
+        pool.add(Affix::prefix("Sharp", StatField::Attack, 2, 5));
+        pool.add(Affix::prefix("Sturdy", StatField::Defense, 2, 5));
+        pool.add(Affix::prefix("Arcane", StatField::Magic, 3, 6));
+        pool.add(Affix::suffix("of the Bear", StatField::Health, 8, 15));
+        pool.add(Affix::suffix("of the Wolf", StatField::Attack, 3, 6));
+        pool.add(Affix::suffix("of the Turtle", StatField::Defense, 3, 6));
+        pool.add(Affix::suffix("of the Owl", StatField::Magic, 4, 8));
+        pool
+    }
+
+    pub fn add(&mut self, affix: Affix) {
+        match affix.kind {
+            AffixKind::Prefix => self.prefixes.push(affix),
+            AffixKind::Suffix => self.suffixes.push(affix),
+        }
+    }
+
+    pub fn prefixes(&self) -> &[Affix] {
+        &self.prefixes
+    }
+
+    pub fn suffixes(&self) -> &[Affix] {
+        &self.suffixes
+    }
+
+    /// Roll affixes for an item of the given rarity.
+    ///
+    /// An uncommon item gets either a prefix or a suffix, higher tiers get
+    /// one of each. A kind with no affix in the pool is skipped.
+    /// # Examples
+    /// ```
+    /// use game::{AffixPool, Helmet, ItemTrait, Rarity, SeededRng};
+    /// let pool = AffixPool::standard();
+    /// let helmet = pool.roll(Helmet::new(), Rarity::Legendary, &mut SeededRng::new(1));
+    /// let again = pool.roll(Helmet::new(), Rarity::Legendary, &mut SeededRng::new(1));
+    /// assert_eq!(helmet.affixes().len(), 2);
+    /// assert_eq!(helmet.name(), again.name());
+    /// assert_eq!(helmet.stat(), again.stat());
+    /// ```
+    pub fn roll<I: EquipmentTrait, R: Rng + ?Sized>(
+        &self,
+        item: I,
+        rarity: Rarity,
+        rng: &mut R,
+    ) -> RolledItem<I> {
+        let kinds: &[AffixKind] = match rarity.affix_count() {
+            0 => &[],
+            1 if rng.below(2) == 0 => &[AffixKind::Prefix],
+            1 => &[AffixKind::Suffix],
+            _ => &[AffixKind::Prefix, AffixKind::Suffix],
+        };
+        let affixes = kinds
+            .iter()
+            .filter_map(|kind| {
+                let candidates = match kind {
+                    AffixKind::Prefix => &self.prefixes,
+                    AffixKind::Suffix => &self.suffixes,
+                };
+                if candidates.is_empty() {
+                    return None;
+                }
+                let affix = &candidates[rng.below(candidates.len() as u64) as usize];
+                Some(affix.roll(rarity, rng))
+            })
+            .collect();
+        RolledItem::new(item, rarity, affixes)
+    }
+
+    /// Roll the rarity with `Rarity::roll`, then the affixes.
+    pub fn roll_random<I: EquipmentTrait, R: Rng + ?Sized>(
+        &self,
+        item: I,
+        rng: &mut R,
+    ) -> RolledItem<I> {
+        let rarity = Rarity::roll(rng);
+        self.roll(item, rarity, rng)
+    }
+}
+
+/// Equipment with a rarity and rolled affixes on top of its own `Stat`.
+pub struct RolledItem<I> {
+    item: I,
+    rarity: Rarity,
+    affixes: Vec<RolledAffix>,
+    name: String,
+}
+
+impl<I: EquipmentTrait> RolledItem<I> {
+    /// Wrap an item, naming it after its affixes as in "Sturdy Helmet of the Bear".
+    pub fn new(item: I, rarity: Rarity, affixes: Vec<RolledAffix>) -> Self {
+        let mut name = item.name().to_string();
+        for affix in &affixes {
+            name = match affix.kind {
+                AffixKind::Prefix => format!("{} {}", affix.name, name),
+                AffixKind::Suffix => format!("{} {}", name, affix.name),
+            };
+        }
+        Self {
+            item,
+            rarity,
+            affixes,
+            name,
+        }
+    }
+
+    pub fn base(&self) -> &I {
+        &self.item
+    }
+
+    pub fn into_base(self) -> I {
+        self.item
+    }
+
+    pub fn rarity(&self) -> Rarity {
+        self.rarity
+    }
+
+    pub fn affixes(&self) -> &[RolledAffix] {
+        &self.affixes
+    }
+}
+
+impl<I: EquipmentTrait> ItemTrait for RolledItem<I> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn base_name(&self) -> &str {
+        self.item.base_name()
+    }
+
+    /// The base item `Stat` plus every affix, saturating on overflow.
+    fn stat(&self) -> Stat {
+        self.affixes.iter().fold(self.item.stat(), |stat, affix| {
+            stat.saturating_add(affix.stat)
+        })
+    }
+
+    fn category(&self) -> ItemCategory {
+        self.item.category()
+    }
+
+    fn weight(&self) -> u32 {
+        self.item.weight()
+    }
+
+    fn modifiers(&self) -> &[StatModifier] {
+        self.item.modifiers()
+    }
+}
+
+impl<I: EquipmentTrait> EquipmentTrait for RolledItem<I> {
+    fn slot(&self) -> EquipmentSlot {
+        self.item.slot()
+    }
+
+    fn set(&self) -> Option<&str> {
+        self.item.set()
+    }
+}
+
+#[cfg(test)]
+mod affix_tests {
+    use super::*;
+    use crate::game::item::{Helmet, Ring, Sword};
+    use crate::game::rng::SeededRng;
+
+    #[test]
+    fn test_common_item_keeps_base_stat() {
+        let mut rng = SeededRng::new(3);
+        let helmet = AffixPool::standard().roll(Helmet::new(), Rarity::Common, &mut rng);
+        assert!(helmet.affixes().is_empty());
+        assert_eq!(helmet.name(), "Helmet");
+        assert_eq!(helmet.stat(), Helmet::new().stat());
+        assert_eq!(helmet.slot(), EquipmentSlot::Head);
+    }
+
+    #[test]
+    fn test_uncommon_item_has_one_affix() {
+        let pool = AffixPool::standard();
+        let mut rng = SeededRng::new(5);
+        for _ in 0..20 {
+            let sword = pool.roll(Sword::new(), Rarity::Uncommon, &mut rng);
+            assert_eq!(sword.affixes().len(), 1);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "affix `Sturdy` has min 5 above max 2")]
+    fn test_affix_bounds_are_checked() {
+        Affix::prefix("Sturdy", StatField::Defense, 5, 2);
+    }
+
+    #[test]
+    fn test_rolled_stat_saturates() {
+        let huge = RolledAffix {
+            name: "Titanic",
+            kind: AffixKind::Prefix,
+            stat: Stat {
+                defense: i32::MAX,
+                ..Stat::default()
+            },
+        };
+        let helmet = RolledItem::new(Helmet::new(), Rarity::Rare, vec![huge, huge]);
+        assert_eq!(helmet.stat().defense, i32::MAX);
+    }
+
+    #[test]
+    fn test_rare_item_is_named_after_its_affixes() {
+        let mut pool = AffixPool::new();
+        pool.add(Affix::prefix("Sturdy", StatField::Defense, 2, 2));
+        pool.add(Affix::suffix("of the Bear", StatField::Health, 10, 10));
+        let helmet = pool.roll(Helmet::new(), Rarity::Rare, &mut SeededRng::new(0));
+        assert_eq!(helmet.name(), "Sturdy Helmet of the Bear");
+        assert_eq!(
+            helmet.stat(),
+            Stat {
+                health: 13,
+                attack: 0,
+                defense: 13,
+                magic: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_rarity_scales_affixes() {
+        let affix = Affix::suffix("of the Bear", StatField::Health, 10, 10);
+        let mut rng = SeededRng::new(0);
+        assert_eq!(affix.roll(Rarity::Uncommon, &mut rng).stat.health, 10);
+        assert_eq!(affix.roll(Rarity::Epic, &mut rng).stat.health, 15);
+        assert_eq!(affix.roll(Rarity::Legendary, &mut rng).stat.health, 20);
+    }
+
+    #[test]
+    fn test_rolled_values_stay_in_range() {
+        let affix = Affix::prefix("Sharp", StatField::Attack, 2, 5);
+        let mut rng = SeededRng::new(11);
+        for _ in 0..100 {
+            let rolled = affix.roll(Rarity::Common, &mut rng);
+            assert!((2..=5).contains(&rolled.stat.attack));
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_item() {
+        let pool = AffixPool::standard();
+        for seed in 0..20 {
+            let first = pool.roll_random(Sword::new(), &mut SeededRng::new(seed));
+            let second = pool.roll_random(Sword::new(), &mut SeededRng::new(seed));
+            assert_eq!(first.rarity(), second.rarity());
+            assert_eq!(first.affixes(), second.affixes());
+            assert_eq!(first.name(), second.name());
+        }
+    }
+
+    #[test]
+    fn test_missing_kind_is_skipped() {
+        let mut pool = AffixPool::new();
+        pool.add(Affix::suffix("of the Owl", StatField::Magic, 4, 4));
+        let ring = pool.roll(Ring::new(), Rarity::Legendary, &mut SeededRng::new(2));
+        assert_eq!(ring.name(), "Ring of the Owl");
+        assert_eq!(ring.modifiers(), Ring::new().modifiers());
+    }
+
+    #[test]
+    fn test_rarity_roll_follows_weights() {
+        let mut rng = SeededRng::new(99);
+        let mut counts = [0; 5];
+        for _ in 0..10_000 {
+            counts[Rarity::roll(&mut rng) as usize] += 1;
+        }
+        assert!(counts[0] > counts[1] && counts[1] > counts[2]);
+        assert!(counts[2] > counts[3] && counts[3] > counts[4]);
+        assert!(counts[4] > 0);
+    }
+
+    #[test]
+    fn test_rarity_order() {
+        assert!(Rarity::Common < Rarity::Legendary);
+        assert_eq!(Rarity::Epic.to_string(), "epic");
+    }
+}
@@ -0,0 +1,737 @@
+use crate::game::character::Character;
+use crate::game::counter::CounterMatrix;
+use crate::game::profession::Profession;
+use crate::game::skill::{Cooldowns, Skill, SkillEffect, SkillTarget};
+use crate::game::stat::{Stat, StatTrait};
+use crate::game::vitals::{VitalsMutTrait, VitalsTrait};
+
+/// Which of the two characters passed to `Battle::fight`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Side {
+    First,
+    Second,
+}
+
+impl Side {
+    pub fn opponent(self) -> Self {
+        match self {
+            Side::First => Side::Second,
+            Side::Second => Side::First,
+        }
+    }
+}
+
+/// A single attack of a battle.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Turn {
+    pub round: u32,
+    pub attacker: Side,
+    /// The attacker was stunned and skipped the turn.
+    pub stunned: bool,
+    /// Health the attacker gained or lost to its status effects at the start of the turn.
+    pub status_health: i32,
+    /// Name of the skill used instead of a plain attack.
+    pub skill: Option<&'static str>,
+    /// Counter multiplier applied to the damage.
+    pub multiplier: f32,
+    pub damage: i32,
+    /// Health of the defender after the attack.
+    pub defender_health: i32,
+}
+
+/// Outcome of `Battle::fight`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BattleResult {
+    /// `None` when both are still standing after the maximum number of rounds.
+    pub winner: Option<Side>,
+    pub rounds: u32,
+    pub turns: Vec<Turn>,
+}
+
+/// Turn based fight between two characters.
+///
+/// Every round the first character attacks, then the second one strikes back
+/// if still alive. At the start of its turn a character's status effects tick,
+/// a stunned character then skips the turn. Damage is the attacker's `attack_points` minus the
+/// defender's `defense_points`, at least 1, scaled by the counter multiplier
+/// from the `CounterMatrix` and rounded. The fight ends as soon as one side
+/// reaches zero health.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Battle {
+    max_rounds: u32,
+    counters: CounterMatrix,
+    skills: bool,
+}
+
+impl Default for Battle {
+    fn default() -> Self {
+        Self {
+            max_rounds: 100,
+            counters: CounterMatrix::standard().clone(),
+            skills: false,
+        }
+    }
+}
+
+impl Battle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_rounds(mut self, max_rounds: u32) -> Self {
+        self.max_rounds = max_rounds;
+        self
+    }
+
+    /// Use `counters` instead of `CounterMatrix::standard` for the damage multipliers.
+    pub fn with_counters(mut self, counters: CounterMatrix) -> Self {
+        self.counters = counters;
+        self
+    }
+
+    /// Let characters use their profession's skills, see `Profession::skills`.
+    ///
+    /// On its turn a character uses the first skill that is off cooldown and
+    /// affordable, healing skills are skipped at full health. Otherwise it
+    /// attacks as usual.
+    pub fn with_skills(mut self, enabled: bool) -> Self {
+        self.skills = enabled;
+        self
+    }
+
+    pub fn counters(&self) -> &CounterMatrix {
+        &self.counters
+    }
+
+    /// Counter multiplier for `attacker` hitting `defender`.
+    pub fn multiplier(&self, attacker: &dyn Profession, defender: &dyn Profession) -> f32 {
+        self.counters
+            .multiplier(attacker.profession_type(), defender.profession_type())
+    }
+
+    /// Damage dealt with the given points and counter multiplier.
+    /// # Examples
+    /// ```
+    /// use game::Battle;
+    /// let battle = Battle::new();
+    /// assert_eq!(battle.damage(40, 30, 1.5), 15);
+    /// assert_eq!(battle.damage(10, 50, 1.0), 1);
+    /// ```
+    pub fn damage(&self, attack_points: i32, defense_points: i32, multiplier: f32) -> i32 {
+        let raw = attack_points.saturating_sub(defense_points).max(1);
+        ((raw as f32 * multiplier).round() as i32).max(1)
+    }
+
+    /// Health restored by a heal with the given magic and power.
+    /// # Examples
+    /// ```
+    /// use game::Battle;
+    /// let battle = Battle::new();
+    /// assert_eq!(battle.heal(60, 0.4), 24);
+    /// assert_eq!(battle.heal(0, 0.4), 0);
+    /// ```
+    pub fn heal(&self, magic: i32, power: f32) -> i32 {
+        (magic as f32 * power).round().max(0.0) as i32
+    }
+
+    /// Fight until a side falls or `max_rounds` rounds are over. The fight is
+    /// a draw when both sides fall, it ends at once when they start fallen.
+    ///
+    /// Stat changes made by skills only last until the end of the fight.
+    /// # Examples
+    /// ```
+    /// use game::{Battle, Character, Knight, Side, Warrior};
+    /// let mut warrior = Character::new("Conan", Warrior::new());
+    /// let mut knight = Character::new("Arthur", Knight::new());
+    /// let result = Battle::new().fight(&mut warrior, &mut knight);
+    /// assert_eq!(result.winner, Some(Side::First));
+    /// assert!(!knight.is_alive());
+    /// ```
+    pub fn fight<A: Profession, B: Profession>(
+        &self,
+        first: &mut Character<A>,
+        second: &mut Character<B>,
+    ) -> BattleResult {
+        let mut first_state = SkillState::default();
+        let mut second_state = SkillState::default();
+        let result = self.run(first, second, &mut first_state, &mut second_state);
+        first_state.revert(first);
+        second_state.revert(second);
+        result
+    }
+
+    fn run<A: Profession, B: Profession>(
+        &self,
+        first: &mut Character<A>,
+        second: &mut Character<B>,
+        first_state: &mut SkillState,
+        second_state: &mut SkillState,
+    ) -> BattleResult {
+        let mut turns = Vec::new();
+        if let Some(winner) = self.outcome(first, second) {
+            return BattleResult {
+                winner,
+                rounds: 0,
+                turns,
+            };
+        }
+        for round in 1..=self.max_rounds {
+            let turn = self.take_turn(round, Side::First, first, second, first_state, second_state);
+            turns.push(turn);
+            if let Some(winner) = self.outcome(first, second) {
+                return BattleResult {
+                    winner,
+                    rounds: round,
+                    turns,
+                };
+            }
+            let turn = self.take_turn(
+                round,
+                Side::Second,
+                second,
+                first,
+                second_state,
+                first_state,
+            );
+            turns.push(turn);
+            if let Some(winner) = self.outcome(first, second) {
+                return BattleResult {
+                    winner,
+                    rounds: round,
+                    turns,
+                };
+            }
+        }
+        BattleResult {
+            winner: None,
+            rounds: self.max_rounds,
+            turns,
+        }
+    }
+
+    fn take_turn<A: Profession, D: Profession>(
+        &self,
+        round: u32,
+        side: Side,
+        attacker: &mut Character<A>,
+        defender: &mut Character<D>,
+        attacker_state: &mut SkillState,
+        defender_state: &mut SkillState,
+    ) -> Turn {
+        let stunned = attacker.is_stunned();
+        let health = attacker.get_vitals().health;
+        attacker.tick_statuses();
+        let status_health = attacker.get_vitals().health - health;
+        if stunned || !attacker.is_alive() {
+            attacker_state.cooldowns.tick();
+            return Turn {
+                round,
+                attacker: side,
+                stunned,
+                status_health,
+                skill: None,
+                multiplier: 1.0,
+                damage: 0,
+                defender_health: defender.get_vitals().health,
+            };
+        }
+        let skill = self.pick_skill(attacker, &attacker_state.cooldowns);
+        attacker_state.cooldowns.tick();
+        let Some(skill) = skill else {
+            let multiplier = self.multiplier(attacker.profession(), defender.profession());
+            let defense_points = defender.defense_points();
+            let damage = self.damage(attacker.attack_points(), defense_points, multiplier);
+            defender.set_vitals(defender.get_vitals().damage(damage));
+            return Turn {
+                round,
+                attacker: side,
+                stunned,
+                status_health,
+                skill: None,
+                multiplier,
+                damage,
+                defender_health: defender.get_vitals().health,
+            };
+        };
+        attacker_state.cooldowns.trigger(&skill);
+        if let Some(vitals) = skill.pay(attacker.get_vitals()) {
+            attacker.set_vitals(vitals);
+        }
+        let (mut multiplier, mut damage) = (1.0, 0);
+        match (skill.target, skill.effect) {
+            (SkillTarget::Opponent, SkillEffect::Damage { power }) => {
+                multiplier = self.multiplier(attacker.profession(), defender.profession());
+                let attack_points = (attacker.attack_points() as f32 * power).round() as i32;
+                damage = self.damage(attack_points, defender.defense_points(), multiplier);
+                defender.set_vitals(defender.get_vitals().damage(damage));
+            }
+            (SkillTarget::User, SkillEffect::Damage { power }) => {
+                let attack_points = (attacker.attack_points() as f32 * power).round() as i32;
+                damage = self.damage(attack_points, attacker.defense_points(), multiplier);
+                attacker.set_vitals(attacker.get_vitals().damage(damage));
+            }
+            (SkillTarget::User, SkillEffect::Heal { power }) => {
+                let amount = self.heal(attacker.get_stat().magic, power);
+                attacker.set_vitals(attacker.get_vitals().heal(amount, &attacker.get_stat()));
+            }
+            (SkillTarget::Opponent, SkillEffect::Heal { power }) => {
+                let amount = self.heal(attacker.get_stat().magic, power);
+                defender.set_vitals(defender.get_vitals().heal(amount, &defender.get_stat()));
+            }
+            (SkillTarget::User, SkillEffect::Status(effect)) => {
+                attacker.add_status(effect);
+            }
+            (SkillTarget::Opponent, SkillEffect::Status(effect)) => {
+                defender.add_status(effect);
+            }
+            (SkillTarget::User, effect) => attacker_state.apply(effect, attacker),
+            (SkillTarget::Opponent, effect) => defender_state.apply(effect, defender),
+        }
+        Turn {
+            round,
+            attacker: side,
+            stunned,
+            status_health,
+            skill: Some(skill.name),
+            multiplier,
+            damage,
+            defender_health: defender.get_vitals().health,
+        }
+    }
+
+    /// First skill of the attacker that is ready, affordable and useful, if
+    /// skills are enabled.
+    fn pick_skill<A: Profession>(
+        &self,
+        attacker: &Character<A>,
+        cooldowns: &Cooldowns,
+    ) -> Option<Skill> {
+        if !self.skills {
+            return None;
+        }
+        let vitals = attacker.get_vitals();
+        let healthy = vitals.health >= attacker.get_stat().health;
+        attacker.profession().skills().into_iter().find(|skill| {
+            let wasted_heal = healthy
+                && skill.target == SkillTarget::User
+                && matches!(skill.effect, SkillEffect::Heal { .. });
+            cooldowns.is_ready(skill) && skill.can_afford(vitals) && !wasted_heal
+        })
+    }
+
+    /// The winner once the fight is over, `Some(None)` when both sides fell.
+    fn outcome<A: Profession, B: Profession>(
+        &self,
+        first: &Character<A>,
+        second: &Character<B>,
+    ) -> Option<Option<Side>> {
+        match (first.is_alive(), second.is_alive()) {
+            (true, true) => None,
+            (true, false) => Some(Some(Side::First)),
+            (false, true) => Some(Some(Side::Second)),
+            (false, false) => Some(None),
+        }
+    }
+}
+
+/// Skill bookkeeping of one side for the length of a fight.
+#[derive(Default)]
+struct SkillState {
+    cooldowns: Cooldowns,
+    /// Sum of the `SkillEffect::ModifyStat` deltas applied to this side.
+    stat_delta: Stat,
+}
+
+impl SkillState {
+    fn apply(&mut self, effect: SkillEffect, target: &mut dyn VitalsMutTrait) {
+        match effect {
+            SkillEffect::Damage { .. } | SkillEffect::Heal { .. } | SkillEffect::Status(_) => {}
+            SkillEffect::ModifyStat(delta) => {
+                target.set_stat(target.get_stat().saturating_add(delta));
+                self.stat_delta = self.stat_delta.saturating_add(delta);
+            }
+        }
+    }
+
+    fn revert(&self, target: &mut dyn VitalsMutTrait) {
+        if self.stat_delta != Stat::default() {
+            target.set_stat(target.get_stat().saturating_sub(self.stat_delta));
+            target.set_vitals(target.get_vitals());
+        }
+    }
+}
+
+#[cfg(test)]
+mod battle_tests {
+    use super::*;
+    use crate::game::item::Wand;
+    use crate::game::profession::{
+        Archer, Cleric, Knight, ProfessionType, Rogue, Sorcerer, Warrior,
+    };
+    use crate::game::stat::StatMutTrait;
+    use crate::game::status::StatusEffect;
+    use crate::game::vitals::Vitals;
+
+    #[test]
+    fn test_damage_is_at_least_one() {
+        let battle = Battle::new();
+        assert_eq!(battle.damage(10, 100, 1.0), 1);
+        assert_eq!(battle.damage(10, 100, 0.1), 1);
+    }
+
+    #[test]
+    fn test_damage_applies_multiplier() {
+        let battle = Battle::new();
+        assert_eq!(battle.damage(50, 20, 1.0), 30);
+        assert_eq!(battle.damage(50, 20, 1.5), 45);
+        assert_eq!(battle.damage(50, 20, 0.75), 23);
+    }
+
+    #[test]
+    fn test_counter_multiplier() {
+        let battle = Battle::new();
+        assert_eq!(battle.multiplier(&Warrior::new(), &Knight::new()), 1.5);
+        assert_eq!(battle.multiplier(&Warrior::new(), &Sorcerer::new()), 0.75);
+        assert_eq!(battle.multiplier(&Warrior::new(), &Warrior::new()), 1.0);
+    }
+
+    #[test]
+    fn test_custom_counters() {
+        let mut counters = CounterMatrix::new();
+        counters.set(ProfessionType::KnightType, ProfessionType::WarriorType, 2.0);
+        let battle = Battle::new().with_counters(counters);
+        assert_eq!(battle.multiplier(&Knight::new(), &Warrior::new()), 2.0);
+        assert_eq!(battle.multiplier(&Warrior::new(), &Knight::new()), 1.0);
+    }
+
+    #[test]
+    fn test_fight_ends_when_health_reaches_zero() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        let result = Battle::new().fight(&mut warrior, &mut knight);
+        assert_eq!(result.winner, Some(Side::First));
+        assert_eq!(knight.get_vitals().health, 0);
+        assert!(warrior.is_alive());
+        // The knight takes (40 - 30) * 1.5 = 15 damage a round.
+        assert_eq!(result.rounds, 7);
+        assert_eq!(result.turns.len(), 13);
+        let last = result.turns.last().unwrap();
+        assert_eq!(last.attacker, Side::First);
+        assert_eq!(last.defender_health, 0);
+    }
+
+    #[test]
+    fn test_fight_between_fallen_characters_ends_at_once() {
+        let mut first = Character::new("Arthur", Knight::new());
+        let mut second = Character::new("Lancelot", Knight::new());
+        first.set_vitals(Vitals { health: 0, mana: 0 });
+        second.set_vitals(Vitals { health: 0, mana: 0 });
+        let result = Battle::new().fight(&mut first, &mut second);
+        assert_eq!(result.winner, None);
+        assert_eq!(result.rounds, 0);
+        assert!(result.turns.is_empty());
+    }
+
+    #[test]
+    fn test_fight_is_over_when_both_sides_fall() {
+        let battle = Battle::new();
+        let mut first = Character::new("Arthur", Knight::new());
+        let mut second = Character::new("Lancelot", Knight::new());
+        assert_eq!(battle.outcome(&first, &second), None);
+        second.set_vitals(Vitals { health: 0, mana: 0 });
+        assert_eq!(battle.outcome(&first, &second), Some(Some(Side::First)));
+        first.set_vitals(Vitals { health: 0, mana: 0 });
+        assert_eq!(battle.outcome(&first, &second), Some(None));
+    }
+
+    #[test]
+    fn test_fight_turns_alternate() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        let result = Battle::new().fight(&mut warrior, &mut knight);
+        assert_eq!(
+            result.turns[0],
+            Turn {
+                round: 1,
+                attacker: Side::First,
+                stunned: false,
+                status_health: 0,
+                skill: None,
+                multiplier: 1.5,
+                damage: 15,
+                defender_health: 85,
+            }
+        );
+        assert_eq!(
+            result.turns[1],
+            Turn {
+                round: 1,
+                attacker: Side::Second,
+                stunned: false,
+                status_health: 0,
+                skill: None,
+                multiplier: 0.75,
+                damage: 1,
+                defender_health: 89,
+            }
+        );
+    }
+
+    #[test]
+    fn test_fight_second_side_can_win() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut sorcerer = Character::new("Merlin", Sorcerer::new());
+        sorcerer.equip(Wand::new()).unwrap();
+        let result = Battle::new().fight(&mut warrior, &mut sorcerer);
+        assert_eq!(result.winner, Some(Side::Second));
+        assert!(!warrior.is_alive());
+    }
+
+    #[test]
+    fn test_fight_with_boxed_professions() {
+        let mut first: Character<Box<dyn Profession>> =
+            Character::new("Conan", Box::new(Warrior::new()));
+        let mut second: Character<Box<dyn Profession>> =
+            Character::new("Arthur", Box::new(Knight::new()));
+        let result = Battle::new().fight(&mut first, &mut second);
+        assert_eq!(result.winner, Some(Side::First));
+        assert_eq!(result.rounds, 7);
+    }
+
+    #[test]
+    fn test_fight_draw_after_max_rounds() {
+        let mut first = Character::new("Arthur", Knight::new());
+        let mut second = Character::new("Lancelot", Knight::new());
+        let result = Battle::new()
+            .with_max_rounds(2)
+            .fight(&mut first, &mut second);
+        assert_eq!(result.winner, None);
+        assert_eq!(result.rounds, 2);
+        assert_eq!(result.turns.len(), 4);
+    }
+
+    #[test]
+    fn test_fight_already_defeated() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        warrior.set_vitals(Vitals { health: 0, mana: 0 });
+        let result = Battle::new().fight(&mut warrior, &mut knight);
+        assert_eq!(result.winner, Some(Side::Second));
+        assert_eq!(result.rounds, 0);
+        assert!(result.turns.is_empty());
+    }
+
+    #[test]
+    fn test_skills_are_off_by_default() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        let result = Battle::new().fight(&mut warrior, &mut knight);
+        assert!(result.turns.iter().all(|turn| turn.skill.is_none()));
+    }
+
+    #[test]
+    fn test_fight_with_skills() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        let result = Battle::new()
+            .with_skills(true)
+            .fight(&mut warrior, &mut knight);
+        // Cleave: (40 * 1.5 - 30) * 1.5 = 45 damage for 8 health.
+        assert_eq!(
+            result.turns[0],
+            Turn {
+                round: 1,
+                attacker: Side::First,
+                stunned: false,
+                status_health: 0,
+                skill: Some("Cleave"),
+                multiplier: 1.5,
+                damage: 45,
+                defender_health: 55,
+            }
+        );
+        assert_eq!(
+            result.turns[1],
+            Turn {
+                round: 1,
+                attacker: Side::Second,
+                stunned: false,
+                status_health: 0,
+                skill: Some("Shield Wall"),
+                multiplier: 1.0,
+                damage: 0,
+                defender_health: 82,
+            }
+        );
+        // The shield wall raises the knight's defense to 40, 1 * 1.5 rounds to 2.
+        assert_eq!(result.turns[2].skill, None);
+        assert_eq!(result.turns[2].damage, 2);
+        assert_eq!(result.winner, Some(Side::First));
+    }
+
+    #[test]
+    fn test_skill_cooldown_skips_turns() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        let result = Battle::new()
+            .with_skills(true)
+            .fight(&mut warrior, &mut knight);
+        let warrior_skills: Vec<_> = result
+            .turns
+            .iter()
+            .filter(|turn| turn.attacker == Side::First)
+            .map(|turn| turn.skill)
+            .take(5)
+            .collect();
+        assert_eq!(
+            warrior_skills,
+            vec![Some("Cleave"), None, None, None, Some("Cleave")]
+        );
+    }
+
+    #[test]
+    fn test_skill_stat_changes_revert_after_fight() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        Battle::new()
+            .with_skills(true)
+            .fight(&mut warrior, &mut knight);
+        assert_eq!(knight.get_stat(), Knight::new().stat());
+    }
+
+    #[test]
+    fn test_skill_needs_mana() {
+        let mut sorcerer = Character::new("Merlin", Sorcerer::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        let result = Battle::new()
+            .with_skills(true)
+            .fight(&mut sorcerer, &mut knight);
+        let fireballs = result
+            .turns
+            .iter()
+            .filter(|turn| turn.skill == Some("Fireball"))
+            .count();
+        // 50 mana pays for two fireballs of 20.
+        assert_eq!(fireballs, 2);
+        assert_eq!(sorcerer.get_vitals().mana, 10);
+    }
+
+    #[test]
+    fn test_heal_skill_waits_for_damage() {
+        let mut cleric = Character::new("Tuck", Cleric::new());
+        let mut archer = Character::new("Robin", Archer::new());
+        let result = Battle::new()
+            .with_skills(true)
+            .with_max_rounds(3)
+            .fight(&mut archer, &mut cleric);
+        let cleric_turns: Vec<_> = result
+            .turns
+            .iter()
+            .filter(|turn| turn.attacker == Side::Second)
+            .map(|turn| turn.skill)
+            .collect();
+        assert_eq!(cleric_turns[0], Some("Heal"));
+        assert!(cleric.get_vitals().health > 0);
+    }
+
+    #[test]
+    fn test_heal_scales_with_magic() {
+        let healed = |magic: i32| {
+            let mut cleric = Character::new("Tuck", Cleric::new());
+            cleric.set_stat(Stat {
+                magic,
+                ..cleric.get_stat()
+            });
+            cleric.set_vitals(Vitals {
+                health: 10,
+                mana: cleric.get_vitals().mana,
+            });
+            let mut archer = Character::new("Robin", Archer::new());
+            let mut cleric_state = SkillState::default();
+            let mut archer_state = SkillState::default();
+            let battle = Battle::new().with_skills(true);
+            let turn = battle.take_turn(
+                1,
+                Side::First,
+                &mut cleric,
+                &mut archer,
+                &mut cleric_state,
+                &mut archer_state,
+            );
+            assert_eq!(turn.skill, Some("Heal"));
+            cleric.get_vitals().health - 10
+        };
+        assert_eq!(healed(60), 24);
+        assert_eq!(healed(100), 40);
+    }
+
+    #[test]
+    fn test_stunned_character_skips_turn() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        knight.add_status(StatusEffect::stun(1));
+        let result = Battle::new()
+            .with_max_rounds(2)
+            .fight(&mut warrior, &mut knight);
+        assert!(result.turns[1].stunned);
+        assert_eq!(result.turns[1].damage, 0);
+        assert_eq!(result.turns[1].defender_health, 90);
+        assert!(!result.turns[3].stunned);
+        assert_eq!(result.turns[3].damage, 1);
+    }
+
+    #[test]
+    fn test_status_damage_ticks_on_own_turn() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        warrior.add_status(StatusEffect::poison(5, 2));
+        let result = Battle::new()
+            .with_max_rounds(3)
+            .fight(&mut warrior, &mut knight);
+        let ticks: Vec<i32> = result.turns.iter().map(|turn| turn.status_health).collect();
+        assert_eq!(ticks, vec![-5, 0, -5, 0, 0, 0]);
+        assert!(warrior.statuses().is_empty());
+    }
+
+    #[test]
+    fn test_status_damage_can_end_the_fight() {
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let mut knight = Character::new("Arthur", Knight::new());
+        warrior.set_vitals(Vitals { health: 3, mana: 0 });
+        warrior.add_status(StatusEffect::burn(5, 2));
+        let result = Battle::new().fight(&mut warrior, &mut knight);
+        assert_eq!(result.winner, Some(Side::Second));
+        assert_eq!(result.rounds, 1);
+        assert_eq!(result.turns.len(), 1);
+        assert_eq!(result.turns[0].damage, 0);
+    }
+
+    #[test]
+    fn test_skill_applies_status() {
+        let mut rogue = Character::new("Robin", Rogue::new());
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let result = Battle::new()
+            .with_skills(true)
+            .with_max_rounds(1)
+            .fight(&mut rogue, &mut warrior);
+        assert_eq!(result.turns[0].skill, Some("Backstab"));
+        let mut rogue = Character::new("Robin", Rogue::new());
+        rogue.set_vitals(Vitals {
+            health: 70,
+            mana: 5,
+        });
+        let mut warrior = Character::new("Conan", Warrior::new());
+        let result = Battle::new()
+            .with_skills(true)
+            .with_max_rounds(1)
+            .fight(&mut rogue, &mut warrior);
+        assert_eq!(result.turns[0].skill, Some("Poison Blade"));
+        assert_eq!(warrior.statuses().get("Poison").unwrap().remaining(), 2);
+    }
+
+    #[test]
+    fn test_side_opponent() {
+        assert_eq!(Side::First.opponent(), Side::Second);
+        assert_eq!(Side::Second.opponent(), Side::First);
+    }
+}
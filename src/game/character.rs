@@ -0,0 +1,779 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::game::equipment::Equipped;
+use crate::game::inventory::{Inventory, InventoryError, InventoryItem, UseOutcome};
+use crate::game::item::EquipmentTrait;
+use crate::game::loadout::{EquipmentSlot, Loadout, LoadoutError};
+use crate::game::profession::Profession;
+use crate::game::progression::{Experience, LevelUp};
+use crate::game::promotion::{PromotionError, PromotionTree};
+use crate::game::stat::{Stat, StatMutTrait, StatTrait};
+use crate::game::status::{StatusEffect, StatusEffects, StatusTick};
+use crate::game::vitals::{Vitals, VitalsMutTrait, VitalsTrait};
+
+/// Where the effective stat of a `Character` comes from.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct StatBreakdown {
+    pub base: Stat,
+    /// Contribution of the loadout, item stats and modifiers.
+    pub equipment: Stat,
+    /// Contribution of the active equipment set bonuses.
+    pub sets: Stat,
+    /// Contribution of buffs, debuffs and other active status effects.
+    pub buffs: Stat,
+    /// Direct changes made through `StatMutTrait::set_stat`, e.g. by potions.
+    pub adjustment: Stat,
+    /// The effective stat, the sum of the above.
+    pub total: Stat,
+}
+
+/// A promotion the character didn't qualify for, handed back along with the reason.
+pub struct PromoteError<P: Profession> {
+    pub character: Box<Character<P>>,
+    pub error: PromotionError,
+}
+
+impl<P: Profession> fmt::Debug for PromoteError<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PromoteError")
+            .field("character", &self.character.name)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<P: Profession> fmt::Display for PromoteError<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl<P: Profession> Error for PromoteError<P> {}
+
+/// A playable entity: a named `Profession` with its own stat, gear and bag.
+///
+/// The base stat starts as the profession's `base_stat` and is kept with the
+/// worn gear in an `Equipped`. `get_stat` returns
+/// the effective stat, i.e. the base with the loadout, its set bonuses and the stat changes of
+/// active status effects applied plus any `set_stat` adjustment, and the current
+/// health and mana are tracked through `VitalsTrait`. All state is owned,
+/// so a character is `Send` and `Sync` whenever its profession is.
+pub struct Character<P: Profession> {
+    name: String,
+    profession: P,
+    equipped: Equipped,
+    /// Applied after the loadout, so `set_stat` lands exactly on its target.
+    adjustment: Stat,
+    experience: Experience,
+    vitals: Vitals,
+    inventory: Inventory,
+    statuses: StatusEffects,
+}
+
+impl<P: Profession> Character<P> {
+    /// Create a character at full health and mana.
+    /// # Examples
+    /// ```
+    /// use game::{Character, StatTrait, Vitals, VitalsTrait, Warrior};
+    /// let character = Character::new("Conan", Warrior::new());
+    /// assert_eq!(character.get_stat(), Warrior::new().stat());
+    /// assert_eq!(character.get_vitals(), Vitals {health: 90, mana: 0});
+    /// ```
+    pub fn new(name: &str, profession: P) -> Self {
+        let base = profession.base_stat();
+        Self {
+            name: name.to_string(),
+            profession,
+            equipped: Equipped::new(base),
+            adjustment: Stat::default(),
+            experience: Experience::new(),
+            vitals: Vitals::full(&base),
+            inventory: Inventory::new(),
+            statuses: StatusEffects::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn profession(&self) -> &P {
+        &self.profession
+    }
+
+    /// Stat without the loadout applied.
+    pub fn base_stat(&self) -> Stat {
+        self.equipped.base()
+    }
+
+    pub fn level(&self) -> u32 {
+        self.experience.level()
+    }
+
+    pub fn experience(&self) -> &Experience {
+        &self.experience
+    }
+
+    /// Add experience, growing the base stat along the profession's curve.
+    ///
+    /// Returns one event per level reached. Current health and mana go up by
+    /// the gained maximums.
+    /// # Examples
+    /// ```
+    /// use game::{Character, Profession, StatTrait, Warrior};
+    /// let mut character = Character::new("Conan", Warrior::new());
+    /// let level_ups = character.gain_xp(100);
+    /// assert_eq!(level_ups.len(), 1);
+    /// assert_eq!(level_ups[0].level, 2);
+    /// assert_eq!(level_ups[0].stat, Warrior::new().stat() + Warrior::new().growth().gain(2));
+    /// assert_eq!(character.get_stat(), level_ups[0].stat);
+    /// ```
+    pub fn gain_xp(&mut self, amount: u32) -> Vec<LevelUp> {
+        let growth = self.profession.growth();
+        let mut level_ups = Vec::new();
+        for level in self.experience.gain(amount) {
+            let gain = growth.gain(level);
+            let base = self.equipped.base().saturating_add(gain);
+            self.equipped.set_base(base);
+            let mut vitals = self.vitals;
+            vitals.health = vitals.health.saturating_add(gain.health.max(0));
+            vitals.mana = vitals.mana.saturating_add(gain.magic.max(0));
+            self.set_vitals(vitals);
+            level_ups.push(LevelUp {
+                level,
+                gain,
+                stat: base,
+            });
+        }
+        level_ups
+    }
+
+    /// Advance into a promoted class, see `PromotionTree::check`.
+    ///
+    /// Level, stat, vitals, gear and bag are kept, later levels grow along the
+    /// new class's curve. The new class must share the `ProfessionType` of the
+    /// current one. On failure the character is handed back unchanged.
+    /// # Examples
+    /// ```
+    /// use game::{Berserker, Character, Profession, PromotionTree, Warrior};
+    /// let mut character = Character::new("Conan", Warrior::new());
+    /// character.gain_xp(20_000);
+    /// let tree = PromotionTree::standard();
+    /// let berserker = character.promote(Berserker::new(), tree).unwrap();
+    /// assert_eq!(berserker.profession().class_name(), "berserker");
+    /// ```
+    pub fn promote<Q: Profession>(
+        self,
+        profession: Q,
+        tree: &PromotionTree,
+    ) -> Result<Character<Q>, PromoteError<P>> {
+        let from = self.profession.class_name();
+        let check = tree
+            .check(from, profession.class_name(), self.level())
+            .and_then(|_| {
+                let (from, to) = (
+                    self.profession.profession_type(),
+                    profession.profession_type(),
+                );
+                if from == to {
+                    Ok(())
+                } else {
+                    Err(PromotionError::ProfessionMismatch { from, to })
+                }
+            });
+        if let Err(error) = check {
+            return Err(PromoteError {
+                character: Box::new(self),
+                error,
+            });
+        }
+        Ok(Character {
+            name: self.name,
+            profession,
+            equipped: self.equipped,
+            adjustment: self.adjustment,
+            experience: self.experience,
+            vitals: self.vitals,
+            inventory: self.inventory,
+            statuses: self.statuses,
+        })
+    }
+
+    pub fn loadout(&self) -> &Loadout {
+        self.equipped.loadout()
+    }
+
+    pub fn inventory(&self) -> &Inventory {
+        &self.inventory
+    }
+
+    pub fn inventory_mut(&mut self) -> &mut Inventory {
+        &mut self.inventory
+    }
+
+    /// Wear an item, see `Loadout::equip`.
+    pub fn equip<I: EquipmentTrait + 'static>(&mut self, item: I) -> Result<(), LoadoutError> {
+        self.equipped.equip(item)?;
+        self.clamp_vitals();
+        Ok(())
+    }
+
+    /// Take off the item worn in `slot`, current vitals drop to the new maximums if needed.
+    pub fn unequip(&mut self, slot: EquipmentSlot) -> Option<Box<dyn EquipmentTrait>> {
+        let item = self.equipped.unequip(slot);
+        self.clamp_vitals();
+        item
+    }
+
+    /// Use the consumable at `index` of the inventory on this character.
+    ///
+    /// The item's `ConsumableTrait::status`, if any, is attached afterwards.
+    /// # Examples
+    /// ```
+    /// use game::prelude::*;
+    /// let mut character = Character::new("Conan", Warrior::new());
+    /// character.inventory_mut().add_consumable(StrengthPotion::new()).unwrap();
+    /// character.use_item(0).unwrap();
+    /// assert_eq!(character.get_stat().attack, 60);
+    /// assert_eq!(character.stat_breakdown().buffs.attack, 20);
+    /// for _ in 0..3 {
+    ///     character.tick_statuses();
+    /// }
+    /// assert_eq!(character.get_stat().attack, 40);
+    /// ```
+    pub fn use_item(&mut self, index: usize) -> Result<UseOutcome, InventoryError> {
+        let status = match self.inventory.get(index) {
+            Some(InventoryItem::Consumable(stack)) => stack.top().status(),
+            _ => None,
+        };
+        let mut inventory = std::mem::take(&mut self.inventory);
+        let outcome = inventory.use_item(index, &mut *self);
+        self.inventory = inventory;
+        if let (Ok(_), Some(status)) = (outcome, status) {
+            self.add_status(status);
+        }
+        outcome
+    }
+
+    pub fn statuses(&self) -> &StatusEffects {
+        &self.statuses
+    }
+
+    /// Attach a status effect, see `StatusEffects::apply`.
+    pub fn add_status(&mut self, effect: StatusEffect) -> bool {
+        let changed = self.statuses.apply(effect);
+        self.clamp_vitals();
+        changed
+    }
+
+    /// Dispel the status effect called `name`.
+    pub fn remove_status(&mut self, name: &str) -> bool {
+        let removed = self.statuses.remove(name).is_some();
+        self.clamp_vitals();
+        removed
+    }
+
+    /// Remove every buff, returning their names.
+    pub fn dispel_buffs(&mut self) -> Vec<Cow<'static, str>> {
+        let removed = self.statuses.dispel_buffs();
+        self.clamp_vitals();
+        removed
+    }
+
+    /// Remove every debuff, returning their names.
+    pub fn dispel_debuffs(&mut self) -> Vec<Cow<'static, str>> {
+        let removed = self.statuses.dispel_debuffs();
+        self.clamp_vitals();
+        removed
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        self.statuses.is_stunned()
+    }
+
+    /// Split the effective stat into base, equipment, sets, buffs and adjustment.
+    pub fn stat_breakdown(&self) -> StatBreakdown {
+        let base = self.equipped.base();
+        let items = self.equipped.loadout().apply_items(base);
+        let equipped = self.equipped.effective_stat();
+        let buffs = self.statuses.stat_delta();
+        StatBreakdown {
+            base,
+            equipment: items.saturating_sub(base),
+            sets: equipped.saturating_sub(items),
+            buffs,
+            adjustment: self.adjustment,
+            total: equipped
+                .saturating_add(buffs)
+                .saturating_add(self.adjustment),
+        }
+    }
+
+    /// Advance the status effects by one tick and apply their health and mana changes.
+    /// # Examples
+    /// ```
+    /// use game::{Character, StatusEffect, VitalsTrait, Warrior};
+    /// let mut character = Character::new("Conan", Warrior::new());
+    /// character.add_status(StatusEffect::poison(5, 2));
+    /// character.tick_statuses();
+    /// assert_eq!(character.get_vitals().health, 85);
+    /// character.tick_statuses();
+    /// assert!(character.statuses().is_empty());
+    /// ```
+    pub fn tick_statuses(&mut self) -> StatusTick {
+        let tick = self.statuses.tick();
+        let vitals = Vitals {
+            health: self.vitals.health.saturating_add(tick.change.health),
+            mana: self.vitals.mana.saturating_add(tick.change.mana),
+        };
+        self.set_vitals(vitals);
+        tick
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.vitals.is_alive()
+    }
+
+    pub fn attack_points(&self) -> i32 {
+        self.profession.attack_points(self)
+    }
+
+    pub fn defense_points(&self) -> i32 {
+        self.profession.defense_points(self)
+    }
+
+    fn clamp_vitals(&mut self) {
+        self.vitals = self.vitals.clamp_to(&self.get_stat());
+    }
+}
+
+impl<P: Profession> StatTrait for Character<P> {
+    fn get_stat(&self) -> Stat {
+        self.stat_breakdown().total
+    }
+}
+
+impl<P: Profession> StatMutTrait for Character<P> {
+    /// Move the effective stat to `stat` by shifting the adjustment by the
+    /// difference, the base and the loadout are left as is.
+    fn set_stat(&mut self, stat: Stat) {
+        let delta = stat.saturating_sub(self.get_stat());
+        self.adjustment = self.adjustment.saturating_add(delta);
+    }
+}
+
+impl<P: Profession> VitalsTrait for Character<P> {
+    fn get_vitals(&self) -> Vitals {
+        self.vitals
+    }
+}
+
+impl<P: Profession> VitalsMutTrait for Character<P> {
+    fn set_vitals(&mut self, vitals: Vitals) {
+        self.vitals = vitals.clamp_to(&self.get_stat());
+    }
+}
+
+#[cfg(test)]
+mod character_tests {
+    use super::*;
+    use crate::game::item::{
+        BloodBag, ChestPlate, Helmet, ItemTrait, Leggings, Ring, StrengthPotion, Sword, Wand,
+    };
+    use crate::game::profession::{Knight, ProfessionType, Sorcerer, Warrior};
+    use crate::game::promotion::{Archmage, Berserker, Promotion};
+
+    #[test]
+    fn test_new_character() {
+        let character = Character::new("Merlin", Sorcerer::new());
+        assert_eq!(character.name(), "Merlin");
+        assert_eq!(character.base_stat(), Sorcerer::new().stat());
+        assert_eq!(character.get_stat(), Sorcerer::new().stat());
+        assert_eq!(
+            character.get_vitals(),
+            Vitals {
+                health: 70,
+                mana: 50
+            }
+        );
+        assert!(character.loadout().is_empty());
+        assert!(character.inventory().is_empty());
+        assert!(character.is_alive());
+    }
+
+    #[test]
+    fn test_equip_changes_effective_stat_only() {
+        let mut character = Character::new("Conan", Warrior::new());
+        character.equip(Sword::new()).unwrap();
+        assert_eq!(character.base_stat(), Warrior::new().stat());
+        assert_eq!(
+            character.get_stat(),
+            Warrior::new().stat() + Sword::new().stat()
+        );
+        character.unequip(EquipmentSlot::MainHand);
+        assert_eq!(character.get_stat(), Warrior::new().stat());
+    }
+
+    #[test]
+    fn test_equip_rejects_occupied_slot() {
+        let mut character = Character::new("Merlin", Sorcerer::new());
+        character.equip(Wand::new()).unwrap();
+        assert_eq!(
+            character.equip(Sword::new()),
+            Err(LoadoutError::SlotOccupied(EquipmentSlot::MainHand))
+        );
+    }
+
+    #[test]
+    fn test_unequip_clamps_vitals() {
+        let mut character = Character::new("Merlin", Sorcerer::new());
+        character.equip(Wand::new()).unwrap();
+        character.set_vitals(Vitals {
+            health: 70,
+            mana: 120,
+        });
+        assert_eq!(character.get_vitals().mana, 120);
+        character.unequip(EquipmentSlot::MainHand);
+        assert_eq!(character.get_vitals().mana, 50);
+    }
+
+    #[test]
+    fn test_item_create_effect_on_character() {
+        let mut character = Character::new("Arthur", Knight::new());
+        character.equip(Helmet::new()).unwrap();
+        Sword::new().create_effect(&mut character);
+        assert_eq!(character.base_stat(), Knight::new().stat());
+        assert_eq!(character.stat_breakdown().adjustment, Sword::new().stat());
+        assert_eq!(
+            character.get_stat(),
+            Knight::new().stat() + Sword::new().stat() + Helmet::new().stat()
+        );
+    }
+
+    #[test]
+    fn test_set_stat_with_percent_modifier() {
+        let mut character = Character::new("Arthur", Knight::new());
+        character.equip(Ring::new()).unwrap();
+        let stat = Stat {
+            defense: 43,
+            ..character.get_stat()
+        };
+        character.set_stat(stat);
+        assert_eq!(character.get_stat(), stat);
+        assert_eq!(character.base_stat(), Knight::new().stat());
+    }
+
+    #[test]
+    fn test_use_item_heals_character() {
+        let mut character = Character::new("Arthur", Knight::new());
+        character
+            .inventory_mut()
+            .add_consumable(BloodBag::new())
+            .unwrap();
+        character.set_vitals(Vitals {
+            health: 50,
+            mana: 0,
+        });
+        assert_eq!(character.use_item(0), Ok(UseOutcome::Consumed));
+        assert_eq!(character.get_vitals().health, 80);
+        assert!(character.inventory().is_empty());
+    }
+
+    #[test]
+    fn test_set_vitals_caps_at_max() {
+        let mut character = Character::new("Arthur", Knight::new());
+        character.set_vitals(Vitals {
+            health: 500,
+            mana: 10,
+        });
+        assert_eq!(
+            character.get_vitals(),
+            Vitals {
+                health: 100,
+                mana: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_gain_xp_levels_up() {
+        let mut character = Character::new("Arthur", Knight::new());
+        character.set_vitals(Vitals {
+            health: 50,
+            mana: 0,
+        });
+        let level_ups = character.gain_xp(350);
+        assert_eq!(character.level(), 3);
+        assert_eq!(character.experience().xp(), 50);
+        assert_eq!(
+            level_ups.iter().map(|l| l.level).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        let growth = Knight::new().growth();
+        assert_eq!(level_ups[1].stat, growth.stat_at(Knight::new().stat(), 3));
+        assert_eq!(character.base_stat(), level_ups[1].stat);
+        assert_eq!(character.get_vitals().health, 50 + 14 + 12);
+    }
+
+    #[test]
+    fn test_gain_xp_without_level_up() {
+        let mut character = Character::new("Merlin", Sorcerer::new());
+        assert!(character.gain_xp(10).is_empty());
+        assert_eq!(character.level(), 1);
+        assert_eq!(character.base_stat(), Sorcerer::new().stat());
+    }
+
+    #[test]
+    fn test_gain_xp_keeps_loadout_applied() {
+        let mut character = Character::new("Conan", Warrior::new());
+        character.equip(Sword::new()).unwrap();
+        let level_ups = character.gain_xp(100);
+        assert_eq!(
+            character.get_stat(),
+            level_ups[0].stat + Sword::new().stat()
+        );
+    }
+
+    #[test]
+    fn test_promote() {
+        let mut character = Character::new("Merlin", Sorcerer::new());
+        character.equip(Wand::new()).unwrap();
+        character.gain_xp(20_000);
+        let level = character.level();
+        let base = character.base_stat();
+        let archmage = character
+            .promote(Archmage::new(), PromotionTree::standard())
+            .unwrap();
+        assert_eq!(archmage.level(), level);
+        assert_eq!(archmage.base_stat(), base);
+        assert_eq!(archmage.loadout().len(), 1);
+        assert_eq!(archmage.profession().class_name(), "archmage");
+    }
+
+    #[test]
+    fn test_promote_rejected() {
+        let character = Character::new("Merlin", Sorcerer::new());
+        let err = character
+            .promote(Archmage::new(), PromotionTree::standard())
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.error,
+            PromotionError::LevelTooLow {
+                required: 20,
+                level: 1
+            }
+        );
+        assert_eq!(err.character.name(), "Merlin");
+        let err = err
+            .character
+            .promote(Berserker::new(), PromotionTree::standard())
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.error,
+            PromotionError::NotAvailable {
+                from: "sorcerer",
+                to: "berserker"
+            }
+        );
+        assert_eq!(err.to_string(), "sorcerer can't be promoted into berserker");
+    }
+
+    #[test]
+    fn test_promote_keeps_profession_type() {
+        let mut tree = PromotionTree::new();
+        tree.add(Promotion {
+            from: "warrior",
+            to: "archmage",
+            level: 1,
+        })
+        .unwrap();
+        let character = Character::new("Conan", Warrior::new());
+        let err = character.promote(Archmage::new(), &tree).err().unwrap();
+        assert_eq!(
+            err.error,
+            PromotionError::ProfessionMismatch {
+                from: ProfessionType::WarriorType,
+                to: ProfessionType::SorcererType,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "a warrior can't be promoted into a sorcerer"
+        );
+        assert_eq!(err.character.name(), "Conan");
+    }
+
+    #[test]
+    fn test_promoted_growth_applies_after_promotion() {
+        let mut character = Character::new("Conan", Warrior::new());
+        character.gain_xp(20_000);
+        let tree = PromotionTree::standard();
+        let mut berserker = character.promote(Berserker::new(), tree).unwrap();
+        let level_ups = berserker.gain_xp(Experience::xp_to_next(berserker.level()));
+        assert_eq!(
+            level_ups[0].gain,
+            Berserker::new().growth().gain(level_ups[0].level)
+        );
+    }
+
+    #[test]
+    fn test_status_stat_delta_is_temporary() {
+        let mut character = Character::new("Arthur", Knight::new());
+        character.add_status(StatusEffect::shield(15, 1));
+        assert_eq!(character.get_stat().defense, 45);
+        assert_eq!(character.base_stat(), Knight::new().stat());
+        character.tick_statuses();
+        assert_eq!(character.get_stat(), Knight::new().stat());
+    }
+
+    #[test]
+    fn test_tick_statuses_caps_health() {
+        let mut character = Character::new("Arthur", Knight::new());
+        character.set_vitals(Vitals {
+            health: 95,
+            mana: 0,
+        });
+        character.add_status(StatusEffect::regen(10, 3));
+        let tick = character.tick_statuses();
+        assert_eq!(tick.change.health, 10);
+        assert_eq!(character.get_vitals().health, 100);
+    }
+
+    #[test]
+    fn test_remove_status() {
+        let mut character = Character::new("Arthur", Knight::new());
+        character.add_status(StatusEffect::stun(2));
+        assert!(character.is_stunned());
+        assert!(character.remove_status("Stun"));
+        assert!(!character.is_stunned());
+        assert!(!character.remove_status("Stun"));
+    }
+
+    #[test]
+    fn test_stat_breakdown() {
+        let mut character = Character::new("Arthur", Knight::new());
+        character.equip(Helmet::new()).unwrap();
+        let might = Stat {
+            health: 0,
+            attack: 20,
+            defense: 0,
+            magic: 0,
+        };
+        character.add_status(StatusEffect::stat_change("Might", might, 2));
+        let breakdown = character.stat_breakdown();
+        assert_eq!(breakdown.base, Knight::new().stat());
+        assert_eq!(breakdown.equipment, Helmet::new().stat());
+        assert_eq!(breakdown.buffs, might);
+        assert_eq!(breakdown.total, character.get_stat());
+        assert_eq!(
+            breakdown.total,
+            Knight::new().stat() + Helmet::new().stat() + might
+        );
+        assert_eq!(breakdown.sets, Stat::default());
+    }
+
+    #[test]
+    fn test_stat_breakdown_shows_set_bonus() {
+        let mut character = Character::new("Arthur", Knight::new());
+        character.equip(Helmet::new()).unwrap();
+        character.equip(ChestPlate::new()).unwrap();
+        let breakdown = character.stat_breakdown();
+        assert_eq!(
+            breakdown.equipment,
+            Helmet::new().stat() + ChestPlate::new().stat()
+        );
+        assert_eq!(
+            breakdown.sets,
+            Stat {
+                health: 0,
+                attack: 0,
+                defense: 5,
+                magic: 0
+            }
+        );
+        assert_eq!(
+            breakdown.total,
+            breakdown.base + breakdown.equipment + breakdown.sets
+        );
+        character.equip(Leggings::new()).unwrap();
+        let breakdown = character.stat_breakdown();
+        // (30 + 35 + 5) * 1.1 = 77 defense, +20 health.
+        assert_eq!(
+            breakdown.sets,
+            Stat {
+                health: 20,
+                attack: 0,
+                defense: 7 + 5,
+                magic: 0
+            }
+        );
+        assert_eq!(character.get_stat().defense, 77);
+    }
+
+    #[test]
+    fn test_potion_buff_reverts() {
+        let mut character = Character::new("Conan", Warrior::new());
+        character
+            .inventory_mut()
+            .add_consumable(StrengthPotion::new())
+            .unwrap();
+        assert_eq!(character.use_item(0), Ok(UseOutcome::Consumed));
+        assert_eq!(character.statuses().buffs().count(), 1);
+        assert_eq!(character.base_stat(), Warrior::new().stat());
+        assert_eq!(character.get_stat().attack, 60);
+        for _ in 0..3 {
+            character.tick_statuses();
+        }
+        assert_eq!(character.get_stat(), Warrior::new().stat());
+    }
+
+    #[test]
+    fn test_dispel_buffs_and_debuffs() {
+        let mut character = Character::new("Conan", Warrior::new());
+        let weaken = Stat {
+            health: -20,
+            attack: -10,
+            defense: 0,
+            magic: 0,
+        };
+        character.add_status(StatusEffect::stat_change("Weaken", weaken, 3));
+        character.add_status(StatusEffect::shield(10, 3));
+        character.add_status(StatusEffect::poison(5, 3));
+        assert_eq!(character.get_vitals().health, 70);
+        assert_eq!(character.dispel_debuffs(), vec!["Weaken", "Poison"]);
+        assert_eq!(character.get_stat().health, 90);
+        assert_eq!(character.dispel_buffs(), vec!["Shield"]);
+        assert_eq!(character.get_stat(), Warrior::new().stat());
+    }
+
+    #[test]
+    fn test_failed_use_item_grants_no_buff() {
+        let mut character = Character::new("Conan", Warrior::new());
+        character
+            .inventory_mut()
+            .add_equipment(Helmet::new())
+            .unwrap();
+        assert!(character.use_item(0).is_err());
+        assert!(character.statuses().is_empty());
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_character_is_send_and_sync() {
+        assert_send_sync::<Character<Warrior>>();
+    }
+
+    #[test]
+    fn test_character_attack_and_defense_points() {
+        let mut character = Character::new("Merlin", Sorcerer::new());
+        character.equip(Wand::new()).unwrap();
+        assert_eq!(character.attack_points(), 120);
+        assert_eq!(character.defense_points(), 20);
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::game::profession::ProfessionType;
+
+#[derive(Debug)]
+pub enum CounterError {
+    Io(io::Error),
+    /// A line doesn't have the `attacker defender multiplier` shape.
+    Malformed {
+        line: usize,
+    },
+    UnknownProfession {
+        line: usize,
+        name: String,
+    },
+    /// The multiplier is not a finite, non-negative number.
+    InvalidMultiplier {
+        line: usize,
+        value: String,
+    },
+}
+
+impl fmt::Display for CounterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CounterError::Io(err) => write!(f, "can't read counter config: {}", err),
+            CounterError::Malformed { line } => {
+                write!(f, "line {}: expected `attacker defender multiplier`", line)
+            }
+            CounterError::UnknownProfession { line, name } => {
+                write!(f, "line {}: unknown profession `{}`", line, name)
+            }
+            CounterError::InvalidMultiplier { line, value } => {
+                write!(f, "line {}: invalid multiplier `{}`", line, value)
+            }
+        }
+    }
+}
+
+impl Error for CounterError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CounterError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CounterError {
+    fn from(err: io::Error) -> Self {
+        CounterError::Io(err)
+    }
+}
+
+/// Damage multipliers for every attacker/defender `ProfessionType` pair.
+///
+/// Pairs that are not listed are neutral and use a multiplier of `1.0`. A
+/// multiplier above `1.0` means the attacker is effective against the
+/// defender, below `1.0` that the attack is weakened.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CounterMatrix {
+    multipliers: HashMap<(ProfessionType, ProfessionType), f32>,
+}
+
+impl CounterMatrix {
+    /// A matrix where every pair is neutral.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The built-in counters, the ones a new `Battle` uses.
+    pub fn standard() -> &'static CounterMatrix {
+        static STANDARD: OnceLock<CounterMatrix> = OnceLock::new();
+        STANDARD.get_or_init(|| {
+            Self::from_config(include_str!("counters.txt"))
+                .expect("the built-in counter config is valid")
+        })
+    }
+
+    pub fn set(&mut self, attacker: ProfessionType, defender: ProfessionType, multiplier: f32) {
+        self.multipliers.insert((attacker, defender), multiplier);
+    }
+
+    pub fn multiplier(&self, attacker: ProfessionType, defender: ProfessionType) -> f32 {
+        self.multipliers
+            .get(&(attacker, defender))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    pub fn is_effective(&self, attacker: ProfessionType, defender: ProfessionType) -> bool {
+        self.multiplier(attacker, defender) > 1.0
+    }
+
+    /// Parse a config with one `attacker defender multiplier` entry per line.
+    ///
+    /// Profession names are case insensitive, blank lines and everything after
+    /// a `#` are ignored. Later entries override earlier ones.
+    /// # Examples
+    /// ```
+    /// use game::{CounterMatrix, ProfessionType};
+    /// let config = "warrior knight 1.25 # attacker defender multiplier";
+    /// let matrix = CounterMatrix::from_config(config).unwrap();
+    /// assert_eq!(matrix.multiplier(ProfessionType::WarriorType, ProfessionType::KnightType), 1.25);
+    /// assert_eq!(matrix.multiplier(ProfessionType::KnightType, ProfessionType::WarriorType), 1.0);
+    /// ```
+    pub fn from_config(config: &str) -> Result<Self, CounterError> {
+        let mut matrix = Self::new();
+        for (index, raw) in config.lines().enumerate() {
+            let line = index + 1;
+            let content = raw.split('#').next().unwrap_or("").trim();
+            if content.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = content.split_whitespace().collect();
+            let [attacker, defender, multiplier] = fields[..] else {
+                return Err(CounterError::Malformed { line });
+            };
+            let attacker = parse_profession(attacker, line)?;
+            let defender = parse_profession(defender, line)?;
+            let multiplier = match multiplier.parse::<f32>() {
+                Ok(value) if value.is_finite() && value >= 0.0 => value,
+                _ => {
+                    return Err(CounterError::InvalidMultiplier {
+                        line,
+                        value: multiplier.to_string(),
+                    })
+                }
+            };
+            matrix.set(attacker, defender, multiplier);
+        }
+        Ok(matrix)
+    }
+
+    /// Read and parse a config file, see `from_config` for the format.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, CounterError> {
+        Self::from_config(&fs::read_to_string(path)?)
+    }
+}
+
+fn parse_profession(name: &str, line: usize) -> Result<ProfessionType, CounterError> {
+    name.parse().map_err(|_| CounterError::UnknownProfession {
+        line,
+        name: name.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod counter_tests {
+    use super::*;
+    use ProfessionType::{KnightType, SorcererType, WarriorType};
+
+    #[test]
+    fn test_new_matrix_is_neutral() {
+        let matrix = CounterMatrix::new();
+        assert_eq!(matrix.multiplier(WarriorType, KnightType), 1.0);
+        assert!(!matrix.is_effective(WarriorType, KnightType));
+    }
+
+    #[test]
+    fn test_standard_matrix_triangle() {
+        let matrix = CounterMatrix::standard();
+        assert_eq!(matrix.multiplier(WarriorType, KnightType), 1.5);
+        assert_eq!(matrix.multiplier(KnightType, SorcererType), 1.5);
+        assert_eq!(matrix.multiplier(SorcererType, WarriorType), 1.5);
+        assert_eq!(matrix.multiplier(KnightType, WarriorType), 0.75);
+        assert_eq!(matrix.multiplier(SorcererType, KnightType), 0.75);
+        assert_eq!(matrix.multiplier(WarriorType, SorcererType), 0.75);
+        assert_eq!(matrix.multiplier(WarriorType, WarriorType), 1.0);
+    }
+
+    #[test]
+    fn test_standard_matrix_counters_are_mirrored() {
+        let matrix = CounterMatrix::standard();
+        for attacker in ProfessionType::iter() {
+            for defender in ProfessionType::iter() {
+                if matrix.is_effective(attacker, defender) {
+                    assert_eq!(
+                        matrix.multiplier(defender, attacker),
+                        0.75,
+                        "{} {}",
+                        defender,
+                        attacker
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_overrides_multiplier() {
+        let mut matrix = CounterMatrix::standard().clone();
+        matrix.set(WarriorType, KnightType, 2.0);
+        assert_eq!(matrix.multiplier(WarriorType, KnightType), 2.0);
+    }
+
+    #[test]
+    fn test_from_config() {
+        let matrix = CounterMatrix::from_config(
+            "# rebalanced\n\nWarrior knight 1.2\nknight  SORCERER\t0.9 # comment\nwarrior knight 1.3\n",
+        )
+        .unwrap();
+        assert_eq!(matrix.multiplier(WarriorType, KnightType), 1.3);
+        assert_eq!(matrix.multiplier(KnightType, SorcererType), 0.9);
+        assert_eq!(matrix.multiplier(SorcererType, WarriorType), 1.0);
+    }
+
+    #[test]
+    fn test_from_config_malformed() {
+        let err = CounterMatrix::from_config("warrior knight\n").unwrap_err();
+        assert!(matches!(err, CounterError::Malformed { line: 1 }));
+    }
+
+    #[test]
+    fn test_from_config_unknown_profession() {
+        let err = CounterMatrix::from_config("\nwarrior bard 1.5").unwrap_err();
+        assert!(
+            matches!(err, CounterError::UnknownProfession { line: 2, ref name } if name == "bard")
+        );
+    }
+
+    #[test]
+    fn test_from_config_invalid_multiplier() {
+        for value in ["fast", "-1", "inf", "NaN"] {
+            let err = CounterMatrix::from_config(&format!("warrior knight {}", value)).unwrap_err();
+            assert!(
+                matches!(err, CounterError::InvalidMultiplier { line: 1, .. }),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let err = CounterMatrix::load("does/not/exist.txt").unwrap_err();
+        assert!(matches!(err, CounterError::Io(_)));
+    }
+
+    #[test]
+    fn test_load_file() {
+        let name = format!("rust_games_counter_test_{}.txt", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        fs::write(&path, "sorcerer warrior 3\n").unwrap();
+        let matrix = CounterMatrix::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(matrix.multiplier(SorcererType, WarriorType), 3.0);
+    }
+}
@@ -0,0 +1,822 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::game::inventory::{Inventory, InventoryItem};
+use crate::game::item::{ConsumableTrait, EquipmentTrait, ItemCategory, ItemError, ItemTrait};
+use crate::game::loadout::EquipmentSlot;
+use crate::game::loot::ItemKind;
+use crate::game::stat::{OverflowPolicy, Stat, StatError};
+use crate::game::status::StatusEffect;
+use crate::game::vitals::VitalsMutTrait;
+
+const SLOTS: [EquipmentSlot; 6] = [
+    EquipmentSlot::Head,
+    EquipmentSlot::Chest,
+    EquipmentSlot::Legs,
+    EquipmentSlot::MainHand,
+    EquipmentSlot::OffHand,
+    EquipmentSlot::Finger,
+];
+
+#[derive(Debug)]
+pub enum RecipeError {
+    Io(io::Error),
+    /// A line doesn't have the `output = ingredients -> kind` shape.
+    Malformed {
+        line: usize,
+    },
+    /// An ingredient count is not a positive number.
+    InvalidCount {
+        line: usize,
+        value: String,
+    },
+    /// An ingredient is neither a base item nor the output of an earlier recipe.
+    UnknownItem {
+        line: usize,
+        name: String,
+    },
+    /// The output kind is neither `consumable` nor an equipment slot.
+    UnknownOutput {
+        line: usize,
+        value: String,
+    },
+    /// The output name is already taken by an item or another recipe.
+    Duplicate {
+        line: usize,
+        name: String,
+    },
+}
+
+impl fmt::Display for RecipeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RecipeError::Io(err) => write!(f, "can't read recipe config: {}", err),
+            RecipeError::Malformed { line } => {
+                write!(f, "line {}: expected `output = ingredients -> kind`", line)
+            }
+            RecipeError::InvalidCount { line, value } => {
+                write!(f, "line {}: invalid ingredient count `{}`", line, value)
+            }
+            RecipeError::UnknownItem { line, name } => {
+                write!(f, "line {}: unknown item `{}`", line, name)
+            }
+            RecipeError::UnknownOutput { line, value } => {
+                write!(f, "line {}: unknown output kind `{}`", line, value)
+            }
+            RecipeError::Duplicate { line, name } => {
+                write!(f, "line {}: `{}` is already defined", line, name)
+            }
+        }
+    }
+}
+
+impl Error for RecipeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RecipeError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RecipeError {
+    fn from(err: io::Error) -> Self {
+        RecipeError::Io(err)
+    }
+}
+
+/// An ingredient short in the inventory.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MissingIngredient {
+    pub name: String,
+    pub required: usize,
+    pub available: usize,
+}
+
+impl fmt::Display for MissingIngredient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} (have {} of {})",
+            self.required.saturating_sub(self.available),
+            self.name,
+            self.available,
+            self.required
+        )
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CraftError {
+    UnknownRecipe(String),
+    /// Every ingredient the inventory is short of, nothing was consumed.
+    Missing(Vec<MissingIngredient>),
+}
+
+impl fmt::Display for CraftError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CraftError::UnknownRecipe(name) => write!(f, "no recipe for `{}`", name),
+            CraftError::Missing(missing) => {
+                let missing: Vec<String> = missing.iter().map(|m| m.to_string()).collect();
+                write!(f, "missing {}", missing.join(", "))
+            }
+        }
+    }
+}
+
+impl Error for CraftError {}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Ingredient {
+    pub name: String,
+    pub count: usize,
+}
+
+/// What a `Recipe` produces.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecipeOutput {
+    Equipment(EquipmentSlot),
+    Consumable,
+}
+
+/// Items consumed from an `Inventory` to craft a new one.
+///
+/// The crafted `Stat` is the sum of the ingredient stats. Ingredients match
+/// on the base item, so a rolled `Sturdy Helmet` counts as a `Helmet`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Recipe {
+    pub name: String,
+    pub ingredients: Vec<Ingredient>,
+    pub output: RecipeOutput,
+}
+
+impl Recipe {
+    /// Ingredients the inventory doesn't hold enough of, empty when the
+    /// recipe can be crafted.
+    pub fn missing(&self, inventory: &Inventory) -> Vec<MissingIngredient> {
+        self.ingredients
+            .iter()
+            .filter_map(|ingredient| {
+                let available = inventory.count(&ingredient.name);
+                (available < ingredient.count).then(|| MissingIngredient {
+                    name: ingredient.name.clone(),
+                    required: ingredient.count,
+                    available,
+                })
+            })
+            .collect()
+    }
+
+    /// Consume the ingredients and build the output, the inventory is left
+    /// untouched when any ingredient is missing.
+    ///
+    /// Ingredients are counted per item, not per charge: a consumable with
+    /// several charges left is one ingredient and its other charges are lost.
+    /// The crafted consumable has a single charge.
+    /// # Examples
+    /// ```
+    /// use game::{BloodBag, CraftError, Crafted, Inventory, ItemTrait, RecipeBook};
+    /// let recipe = RecipeBook::standard().get("Greater Blood Bag").unwrap();
+    /// let mut inventory = Inventory::new();
+    /// inventory.add_consumable(BloodBag::new()).unwrap();
+    /// let Err(CraftError::Missing(missing)) = recipe.craft(&mut inventory) else {
+    ///     panic!("only one bag");
+    /// };
+    /// assert_eq!(missing[0].to_string(), "2 Blood Bag (have 1 of 3)");
+    ///
+    /// inventory.add_consumable(BloodBag::new()).unwrap();
+    /// inventory.add_consumable(BloodBag::new()).unwrap();
+    /// let Ok(Crafted::Consumable(bag)) = recipe.craft(&mut inventory) else {
+    ///     panic!("three bags");
+    /// };
+    /// assert_eq!(bag.stat().health, 90);
+    /// assert!(inventory.is_empty());
+    /// ```
+    pub fn craft(&self, inventory: &mut Inventory) -> Result<Crafted, CraftError> {
+        let missing = self.missing(inventory);
+        if !missing.is_empty() {
+            return Err(CraftError::Missing(missing));
+        }
+        let mut stat = Stat::default();
+        let mut weight: u32 = 0;
+        for ingredient in &self.ingredients {
+            for entry in inventory.take(&ingredient.name, ingredient.count) {
+                match entry {
+                    InventoryItem::Equipment(item) => {
+                        stat = stat.saturating_add(item.stat());
+                        weight = weight.saturating_add(item.weight());
+                    }
+                    InventoryItem::Consumable(stack) => {
+                        for item in stack.iter() {
+                            stat = stat.saturating_add(item.stat());
+                            weight = weight.saturating_add(item.weight());
+                        }
+                    }
+                }
+            }
+        }
+        let name = self.name.clone();
+        Ok(match self.output {
+            RecipeOutput::Equipment(slot) => Crafted::Equipment(CraftedEquipment {
+                name,
+                stat,
+                weight,
+                slot,
+            }),
+            RecipeOutput::Consumable => Crafted::Consumable(CraftedConsumable {
+                name,
+                stat,
+                charges: 1,
+            }),
+        })
+    }
+}
+
+/// Recipes looked up by output name.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct RecipeBook {
+    recipes: Vec<Recipe>,
+}
+
+impl RecipeBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The built-in recipes.
+    pub fn standard() -> &'static RecipeBook {
+        static STANDARD: OnceLock<RecipeBook> = OnceLock::new();
+        STANDARD.get_or_init(|| {
+            Self::from_config(include_str!("recipes.txt"))
+                .expect("the built-in recipe config is valid")
+        })
+    }
+
+    /// Parse a config with one `output = ingredients -> kind` recipe per line.
+    ///
+    /// Ingredients are separated by `+` and may start with a count. They must
+    /// name a base item or the output of an earlier recipe, ignoring case.
+    /// The kind is `consumable` or the equipment slot of the output. Blank
+    /// lines and everything after a `#` are ignored.
+    /// # Examples
+    /// ```
+    /// use game::{EquipmentSlot, RecipeBook, RecipeError, RecipeOutput};
+    /// let book = RecipeBook::from_config("Twin Blades = 2 sword -> main hand").unwrap();
+    /// let recipe = book.get("Twin Blades").unwrap();
+    /// assert_eq!(recipe.ingredients[0].name, "Sword");
+    /// assert_eq!(recipe.output, RecipeOutput::Equipment(EquipmentSlot::MainHand));
+    ///
+    /// let err = RecipeBook::from_config("\nBroken = 2 Stick -> consumable").unwrap_err();
+    /// assert_eq!(err.to_string(), "line 2: unknown item `Stick`");
+    /// ```
+    pub fn from_config(config: &str) -> Result<Self, RecipeError> {
+        let mut book = Self::new();
+        for (index, raw) in config.lines().enumerate() {
+            let line = index + 1;
+            let content = raw.split('#').next().unwrap_or("").trim();
+            if content.is_empty() {
+                continue;
+            }
+            let recipe = book.parse_recipe(content, line)?;
+            book.recipes.push(recipe);
+        }
+        Ok(book)
+    }
+
+    /// Read and parse a config file, see `from_config` for the format.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, RecipeError> {
+        Self::from_config(&fs::read_to_string(path)?)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Recipe> {
+        self.recipes
+            .iter()
+            .find(|recipe| recipe.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Recipe> {
+        self.recipes.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.recipes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recipes.is_empty()
+    }
+
+    /// Craft the recipe called `name` from the inventory, see `Recipe::craft`.
+    pub fn craft(&self, name: &str, inventory: &mut Inventory) -> Result<Crafted, CraftError> {
+        self.get(name)
+            .ok_or_else(|| CraftError::UnknownRecipe(name.to_string()))?
+            .craft(inventory)
+    }
+
+    /// The canonical name of a base item or earlier recipe output.
+    fn known_item(&self, name: &str) -> Option<String> {
+        ItemKind::ALL
+            .iter()
+            .map(|kind| kind.name())
+            .chain(self.recipes.iter().map(|recipe| recipe.name.as_str()))
+            .find(|known| known.eq_ignore_ascii_case(name))
+            .map(str::to_string)
+    }
+
+    fn parse_recipe(&self, content: &str, line: usize) -> Result<Recipe, RecipeError> {
+        let (name, rest) = content
+            .split_once('=')
+            .ok_or(RecipeError::Malformed { line })?;
+        let (ingredients, output) = rest
+            .split_once("->")
+            .ok_or(RecipeError::Malformed { line })?;
+        let name = name.trim();
+        if name.is_empty() || ingredients.trim().is_empty() {
+            return Err(RecipeError::Malformed { line });
+        }
+        if self.known_item(name).is_some() {
+            return Err(RecipeError::Duplicate {
+                line,
+                name: name.to_string(),
+            });
+        }
+        let mut parsed: Vec<Ingredient> = Vec::new();
+        for ingredient in ingredients.split('+') {
+            let ingredient = parse_ingredient(ingredient.trim(), line)?;
+            let name = self
+                .known_item(&ingredient.name)
+                .ok_or(RecipeError::UnknownItem {
+                    line,
+                    name: ingredient.name,
+                })?;
+            match parsed.iter_mut().find(|known| known.name == name) {
+                Some(known) => known.count += ingredient.count,
+                None => parsed.push(Ingredient {
+                    name,
+                    count: ingredient.count,
+                }),
+            }
+        }
+        Ok(Recipe {
+            name: name.to_string(),
+            ingredients: parsed,
+            output: parse_output(output.trim(), line)?,
+        })
+    }
+}
+
+fn parse_ingredient(content: &str, line: usize) -> Result<Ingredient, RecipeError> {
+    if content.is_empty() {
+        return Err(RecipeError::Malformed { line });
+    }
+    let (count, name) = match content.split_once(char::is_whitespace) {
+        Some((count, name)) if count.starts_with(|c: char| c.is_ascii_digit()) => (count, name),
+        _ => {
+            return Ok(Ingredient {
+                name: content.to_string(),
+                count: 1,
+            })
+        }
+    };
+    match count.parse::<usize>() {
+        Ok(count) if count > 0 => Ok(Ingredient {
+            name: name.trim().to_string(),
+            count,
+        }),
+        _ => Err(RecipeError::InvalidCount {
+            line,
+            value: count.to_string(),
+        }),
+    }
+}
+
+fn parse_output(content: &str, line: usize) -> Result<RecipeOutput, RecipeError> {
+    if content.eq_ignore_ascii_case("consumable") {
+        return Ok(RecipeOutput::Consumable);
+    }
+    SLOTS
+        .iter()
+        .find(|slot| slot.to_string().eq_ignore_ascii_case(content))
+        .map(|slot| RecipeOutput::Equipment(*slot))
+        .ok_or_else(|| RecipeError::UnknownOutput {
+            line,
+            value: content.to_string(),
+        })
+}
+
+/// The item a `Recipe` produced.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Crafted {
+    Equipment(CraftedEquipment),
+    Consumable(CraftedConsumable),
+}
+
+impl Crafted {
+    pub fn item(&self) -> &dyn ItemTrait {
+        match self {
+            Crafted::Equipment(item) => item,
+            Crafted::Consumable(item) => item,
+        }
+    }
+}
+
+/// Equipment crafted from other items, weighing as much as they did.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CraftedEquipment {
+    name: String,
+    stat: Stat,
+    weight: u32,
+    slot: EquipmentSlot,
+}
+
+impl ItemTrait for CraftedEquipment {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn category(&self) -> ItemCategory {
+        ItemCategory::Equipment
+    }
+
+    fn weight(&self) -> u32 {
+        self.weight
+    }
+}
+
+impl EquipmentTrait for CraftedEquipment {
+    fn slot(&self) -> EquipmentSlot {
+        self.slot
+    }
+}
+
+/// A consumable crafted from other items.
+///
+/// Restores its health and magic as health and mana, and grants its attack
+/// and defense as a 3 turn buff named after the recipe.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CraftedConsumable {
+    name: String,
+    stat: Stat,
+    charges: u32,
+}
+
+impl ItemTrait for CraftedConsumable {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn category(&self) -> ItemCategory {
+        ItemCategory::Consumable
+    }
+
+    fn create_effect_with<T: VitalsMutTrait + ?Sized>(
+        &self,
+        target: &mut T,
+        _policy: OverflowPolicy,
+    ) -> Result<(), StatError> {
+        let stat = target.get_stat();
+        let vitals = target
+            .get_vitals()
+            .heal(self.stat.health, &stat)
+            .restore_mana(self.stat.magic, &stat);
+        target.set_vitals(vitals);
+        Ok(())
+    }
+}
+
+impl ConsumableTrait for CraftedConsumable {
+    fn charges(&self) -> u32 {
+        self.charges
+    }
+
+    fn consume(&mut self, target: &mut dyn VitalsMutTrait) -> Result<u32, ItemError> {
+        if self.charges == 0 {
+            return Err(ItemError::Depleted);
+        }
+        self.create_effect(target);
+        self.charges -= 1;
+        Ok(self.charges)
+    }
+
+    fn status(&self) -> Option<StatusEffect> {
+        let delta = Stat {
+            attack: self.stat.attack,
+            defense: self.stat.defense,
+            ..Stat::default()
+        };
+        (delta != Stat::default()).then(|| StatusEffect::stat_change(self.name.clone(), delta, 3))
+    }
+}
+
+#[cfg(test)]
+mod crafting_tests {
+    use super::*;
+    use crate::game::affix::{AffixKind, Rarity, RolledAffix, RolledItem};
+    use crate::game::item::{BloodBag, ChestPlate, Helmet, Leggings, StrengthPotion, Sword};
+
+    #[test]
+    fn test_standard_recipes() {
+        let book = RecipeBook::standard();
+        assert_eq!(book.len(), 3);
+        let plate = book.get("plate set").unwrap();
+        assert_eq!(plate.output, RecipeOutput::Equipment(EquipmentSlot::Chest));
+        assert_eq!(plate.ingredients.len(), 3);
+        let bag = book.get("Greater Blood Bag").unwrap();
+        assert_eq!(
+            bag.ingredients,
+            vec![Ingredient {
+                name: "Blood Bag".to_string(),
+                count: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn test_craft_plate_set() {
+        let mut inventory = Inventory::new();
+        inventory.add_equipment(Helmet::new()).unwrap();
+        inventory.add_equipment(Sword::new()).unwrap();
+        inventory.add_equipment(ChestPlate::new()).unwrap();
+        inventory.add_equipment(Leggings::new()).unwrap();
+        let crafted = RecipeBook::standard().craft("Plate Set", &mut inventory);
+        let Ok(Crafted::Equipment(plate)) = crafted else {
+            panic!("all pieces are there");
+        };
+        assert_eq!(plate.name(), "Plate Set");
+        assert_eq!(plate.stat().defense, 35);
+        assert_eq!(plate.weight(), 16);
+        assert_eq!(plate.slot(), EquipmentSlot::Chest);
+        assert_eq!(inventory.len(), 1);
+        assert_eq!(inventory.count("Sword"), 1);
+    }
+
+    #[test]
+    fn test_missing_ingredient_display_saturates() {
+        let missing = MissingIngredient {
+            name: "Sword".to_string(),
+            required: 1,
+            available: 3,
+        };
+        assert_eq!(missing.to_string(), "0 Sword (have 3 of 1)");
+    }
+
+    #[test]
+    fn test_charges_of_ingredients_are_not_counted() {
+        let mut inventory = Inventory::new();
+        inventory.add_consumable(BloodBag::with_charges(5)).unwrap();
+        inventory.add_consumable(StrengthPotion::new()).unwrap();
+        let Ok(Crafted::Consumable(elixir)) =
+            RecipeBook::standard().craft("Elixir of Might", &mut inventory)
+        else {
+            panic!("both ingredients are there");
+        };
+        assert_eq!(elixir.charges(), 1);
+        assert_eq!(elixir.stat().health, 30);
+        assert!(inventory.is_empty());
+    }
+
+    #[test]
+    fn test_missing_ingredients_are_reported() {
+        let mut inventory = Inventory::new();
+        inventory.add_equipment(Helmet::new()).unwrap();
+        let err = RecipeBook::standard()
+            .craft("Plate Set", &mut inventory)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            CraftError::Missing(vec![
+                MissingIngredient {
+                    name: "Chest Plate".to_string(),
+                    required: 1,
+                    available: 0
+                },
+                MissingIngredient {
+                    name: "Leggings".to_string(),
+                    required: 1,
+                    available: 0
+                },
+            ])
+        );
+        assert_eq!(
+            err.to_string(),
+            "missing 1 Chest Plate (have 0 of 1), 1 Leggings (have 0 of 1)"
+        );
+        assert_eq!(inventory.len(), 1);
+    }
+
+    #[test]
+    fn test_unknown_recipe() {
+        let mut inventory = Inventory::new();
+        assert_eq!(
+            RecipeBook::standard()
+                .craft("Moon Blade", &mut inventory)
+                .unwrap_err(),
+            CraftError::UnknownRecipe("Moon Blade".to_string())
+        );
+    }
+
+    #[test]
+    fn test_crafted_consumable_heals_and_buffs() {
+        let mut inventory = Inventory::new();
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        inventory.add_consumable(StrengthPotion::new()).unwrap();
+        let Ok(Crafted::Consumable(elixir)) =
+            RecipeBook::standard().craft("Elixir of Might", &mut inventory)
+        else {
+            panic!("both ingredients are there");
+        };
+        assert_eq!(
+            elixir.stat(),
+            Stat {
+                health: 30,
+                attack: 20,
+                defense: 0,
+                magic: 0
+            }
+        );
+        let buff = elixir.status().unwrap();
+        assert_eq!(buff.name, "Elixir of Might");
+        assert_eq!(
+            buff.stat,
+            Stat {
+                attack: 20,
+                ..Stat::default()
+            }
+        );
+        assert!(inventory.is_empty());
+    }
+
+    #[test]
+    fn test_rolled_items_match_their_base_item() {
+        let sturdy = RolledAffix {
+            name: "Sturdy",
+            kind: AffixKind::Prefix,
+            stat: Stat {
+                defense: 3,
+                ..Stat::default()
+            },
+        };
+        let helmet = RolledItem::new(Helmet::new(), Rarity::Uncommon, vec![sturdy]);
+        assert_eq!(helmet.name(), "Sturdy Helmet");
+        let mut inventory = Inventory::new();
+        inventory.add_equipment(helmet).unwrap();
+        inventory.add_equipment(ChestPlate::new()).unwrap();
+        inventory.add_equipment(Leggings::new()).unwrap();
+        let crafted = RecipeBook::standard().craft("Plate Set", &mut inventory);
+        let Ok(Crafted::Equipment(plate)) = crafted else {
+            panic!("the sturdy helmet is a helmet");
+        };
+        assert_eq!(plate.stat().defense, 38);
+        assert!(inventory.is_empty());
+    }
+
+    #[test]
+    fn test_depleted_consumables_are_not_ingredients() {
+        let spent = CraftedConsumable {
+            name: "Blood Bag".to_string(),
+            stat: BloodBag::new().stat(),
+            charges: 0,
+        };
+        let mut inventory = Inventory::new();
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        assert!(inventory.add_consumable(spent).is_err());
+        inventory.add_consumable(StrengthPotion::new()).unwrap();
+        let book = RecipeBook::standard();
+        let crafted = book.craft("Greater Blood Bag", &mut inventory);
+        assert!(matches!(crafted, Err(CraftError::Missing(_))));
+        assert_eq!(inventory.count("Blood Bag"), 1);
+    }
+
+    #[test]
+    fn test_crafted_weight_saturates() {
+        let book = RecipeBook::from_config(
+            "Plate Set = Helmet + Chest Plate + Leggings -> chest\n\
+             Fortress = 2 Plate Set -> chest",
+        )
+        .unwrap();
+        let heavy = CraftedEquipment {
+            name: "Plate Set".to_string(),
+            stat: Stat::default(),
+            weight: u32::MAX,
+            slot: EquipmentSlot::Chest,
+        };
+        let mut inventory = Inventory::new();
+        inventory.add_equipment(heavy.clone()).unwrap();
+        inventory.add_equipment(heavy).unwrap();
+        let Ok(crafted) = book.craft("Fortress", &mut inventory) else {
+            panic!("both sets are there");
+        };
+        assert_eq!(crafted.item().weight(), u32::MAX);
+    }
+
+    #[test]
+    fn test_crafted_items_can_be_ingredients() {
+        let book = RecipeBook::from_config(
+            "Greater Blood Bag = 2 blood bag -> consumable\n\
+             Grand Blood Bag = 2 Greater Blood Bag -> consumable",
+        )
+        .unwrap();
+        let mut inventory = Inventory::new();
+        for _ in 0..4 {
+            inventory.add_consumable(BloodBag::new()).unwrap();
+        }
+        for _ in 0..2 {
+            let Ok(Crafted::Consumable(bag)) = book.craft("Greater Blood Bag", &mut inventory)
+            else {
+                panic!("enough bags");
+            };
+            inventory.add_consumable(bag).unwrap();
+        }
+        let Ok(crafted) = book.craft("Grand Blood Bag", &mut inventory) else {
+            panic!("enough greater bags");
+        };
+        assert_eq!(crafted.item().stat().health, 120);
+    }
+
+    #[test]
+    fn test_repeated_ingredients_are_merged() {
+        let book = RecipeBook::from_config("Pair = Sword + sword -> off hand").unwrap();
+        let recipe = book.get("Pair").unwrap();
+        assert_eq!(
+            recipe.ingredients,
+            vec![Ingredient {
+                name: "Sword".to_string(),
+                count: 2
+            }]
+        );
+        assert_eq!(
+            recipe.output,
+            RecipeOutput::Equipment(EquipmentSlot::OffHand)
+        );
+    }
+
+    #[test]
+    fn test_from_config_errors() {
+        let cases = [
+            (
+                "Plate Set helmet -> chest",
+                "line 1: expected `output = ingredients -> kind`",
+            ),
+            (
+                "Plate Set = helmet",
+                "line 1: expected `output = ingredients -> kind`",
+            ),
+            (
+                "Plate Set =  -> chest",
+                "line 1: expected `output = ingredients -> kind`",
+            ),
+            (
+                "Plate Set = helmet + -> chest",
+                "line 1: expected `output = ingredients -> kind`",
+            ),
+            (
+                "Plate Set = 0 helmet -> chest",
+                "line 1: invalid ingredient count `0`",
+            ),
+            (
+                "Plate Set = 2x helmet -> chest",
+                "line 1: invalid ingredient count `2x`",
+            ),
+            ("Plate Set = hat -> chest", "line 1: unknown item `hat`"),
+            (
+                "Plate Set = helmet -> back",
+                "line 1: unknown output kind `back`",
+            ),
+            (
+                "sword = helmet -> chest",
+                "line 1: `sword` is already defined",
+            ),
+            (
+                "# fine\nA = helmet -> head\na = helmet -> head",
+                "line 3: `a` is already defined",
+            ),
+        ];
+        for (config, message) in cases {
+            assert_eq!(
+                RecipeBook::from_config(config).unwrap_err().to_string(),
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let err = RecipeBook::load("does/not/exist.txt").unwrap_err();
+        assert!(matches!(err, RecipeError::Io(_)));
+        assert!(err.source().is_some());
+    }
+}
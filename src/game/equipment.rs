@@ -0,0 +1,159 @@
+use crate::game::item::EquipmentTrait;
+use crate::game::loadout::{EquipmentSlot, Loadout, LoadoutError};
+use crate::game::stat::Stat;
+
+/// A base `Stat` together with the items currently worn, the gear of a
+/// `Character`.
+///
+/// Unlike `ItemTrait::create_effect`, equipping never touches the base stat:
+/// the effective stat is recomputed from the base and the worn items on every
+/// call, so unequipping an item restores the previous values exactly.
+pub struct Equipped {
+    base: Stat,
+    loadout: Loadout,
+}
+
+impl Equipped {
+    pub fn new(base: Stat) -> Self {
+        Self {
+            base,
+            loadout: Loadout::new(),
+        }
+    }
+
+    pub fn base(&self) -> Stat {
+        self.base
+    }
+
+    pub fn set_base(&mut self, base: Stat) {
+        self.base = base;
+    }
+
+    pub fn loadout(&self) -> &Loadout {
+        &self.loadout
+    }
+
+    /// Wear an item, see `Loadout::equip`.
+    pub fn equip<I: EquipmentTrait + 'static>(&mut self, item: I) -> Result<(), LoadoutError> {
+        self.loadout.equip(item)
+    }
+
+    /// Take off the item worn in `slot`.
+    pub fn unequip(&mut self, slot: EquipmentSlot) -> Option<Box<dyn EquipmentTrait>> {
+        self.loadout.unequip(slot)
+    }
+
+    /// Flat `Stat` granted by the worn items, without their modifiers.
+    pub fn bonus(&self) -> Stat {
+        self.loadout.bonus()
+    }
+
+    /// Base stat with the worn items applied, see `Loadout::apply`.
+    /// # Examples
+    /// ```
+    /// use game::{EquipmentSlot, Equipped, Helmet, Stat};
+    /// let base = Stat {health: 90, attack: 40, defense: 55, magic: 0};
+    /// let mut equipped = Equipped::new(base);
+    /// equipped.equip(Helmet::new()).unwrap();
+    /// assert_eq!(equipped.effective_stat().defense, 65);
+    /// equipped.unequip(EquipmentSlot::Head);
+    /// assert_eq!(equipped.effective_stat(), base);
+    /// ```
+    pub fn effective_stat(&self) -> Stat {
+        self.loadout.apply(self.base)
+    }
+}
+
+#[cfg(test)]
+mod equipment_tests {
+    use super::*;
+    use crate::game::item::{ChestPlate, Helmet, Ring, Sword};
+
+    fn base() -> Stat {
+        Stat {
+            health: 90,
+            attack: 40,
+            defense: 55,
+            magic: 0,
+        }
+    }
+
+    #[test]
+    fn test_new_equipped() {
+        let equipped = Equipped::new(base());
+        assert!(equipped.loadout().is_empty());
+        assert_eq!(equipped.bonus(), Stat::default());
+        assert_eq!(equipped.effective_stat(), base());
+    }
+
+    #[test]
+    fn test_equip_adds_item_stat() {
+        let mut equipped = Equipped::new(base());
+        equipped.equip(Helmet::new()).unwrap();
+        equipped.equip(Sword::new()).unwrap();
+        assert_eq!(
+            equipped.effective_stat(),
+            Stat {
+                health: 90,
+                attack: 55,
+                defense: 69,
+                magic: 0
+            }
+        );
+        assert_eq!(equipped.base(), base());
+    }
+
+    #[test]
+    fn test_equip_rejects_occupied_slot() {
+        let mut equipped = Equipped::new(base());
+        equipped.equip(Helmet::new()).unwrap();
+        let before = equipped.effective_stat();
+        assert_eq!(
+            equipped.equip(Helmet::new()),
+            Err(LoadoutError::SlotOccupied(EquipmentSlot::Head))
+        );
+        assert_eq!(equipped.effective_stat(), before);
+    }
+
+    #[test]
+    fn test_unequip_restores_previous_stat() {
+        let mut equipped = Equipped::new(base());
+        equipped.equip(ChestPlate::new()).unwrap();
+        let before = equipped.effective_stat();
+        equipped.equip(Ring::new()).unwrap();
+        assert_ne!(equipped.effective_stat(), before);
+        assert!(equipped.unequip(EquipmentSlot::Finger).is_some());
+        assert_eq!(equipped.effective_stat(), before);
+    }
+
+    #[test]
+    fn test_unequip_empty_slot() {
+        let mut equipped = Equipped::new(base());
+        assert!(equipped.unequip(EquipmentSlot::Head).is_none());
+    }
+
+    #[test]
+    fn test_modifiers_apply_after_flat_bonus() {
+        let mut equipped = Equipped::new(base());
+        equipped.equip(Ring::new()).unwrap();
+        equipped.equip(Helmet::new()).unwrap();
+        // (55 + 10) * 1.1 = 71.5
+        assert_eq!(equipped.effective_stat().defense, 72);
+    }
+
+    #[test]
+    fn test_set_base_keeps_items() {
+        let mut equipped = Equipped::new(base());
+        equipped.equip(Helmet::new()).unwrap();
+        equipped.set_base(Stat::default());
+        assert_eq!(
+            equipped.effective_stat(),
+            Stat {
+                health: 0,
+                attack: 0,
+                defense: 10,
+                magic: 0
+            }
+        );
+    }
+}
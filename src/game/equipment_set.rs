@@ -0,0 +1,113 @@
+use crate::game::modifier::StatModifier;
+use crate::game::stat::{Stat, StatField};
+
+/// Extra stat granted once enough pieces of an `EquipmentSet` are worn.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetBonus {
+    pub pieces: usize,
+    pub stat: Stat,
+    pub modifiers: Vec<StatModifier>,
+}
+
+/// Equipment sharing a set name, see `EquipmentTrait::set`.
+///
+/// Bonuses add up, wearing 3 pieces grants both the 2 and the 3 piece bonus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquipmentSet {
+    pub name: &'static str,
+    pub bonuses: Vec<SetBonus>,
+}
+
+impl EquipmentSet {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            bonuses: Vec::new(),
+        }
+    }
+
+    /// Add a bonus granted from `pieces` worn items on.
+    ///
+    /// # Panics
+    /// Panics if `pieces` is zero, such a bonus would apply without the set.
+    pub fn with_bonus(mut self, pieces: usize, stat: Stat, modifiers: Vec<StatModifier>) -> Self {
+        assert!(pieces > 0, "a set bonus needs at least one piece");
+        self.bonuses.push(SetBonus {
+            pieces,
+            stat,
+            modifiers,
+        });
+        self
+    }
+
+    /// The `Helmet`, `ChestPlate` and `Leggings` set.
+    pub fn plate() -> Self {
+        Self::new("Plate")
+            .with_bonus(
+                2,
+                Stat {
+                    health: 0,
+                    attack: 0,
+                    defense: 5,
+                    magic: 0,
+                },
+                Vec::new(),
+            )
+            .with_bonus(
+                3,
+                Stat {
+                    health: 20,
+                    attack: 0,
+                    defense: 0,
+                    magic: 0,
+                },
+                vec![StatModifier::add_percent(StatField::Defense, 10.0)],
+            )
+    }
+
+    /// The sets known to a new `Loadout`.
+    pub fn standard() -> Vec<EquipmentSet> {
+        vec![Self::plate()]
+    }
+
+    /// Bonuses granted when `pieces` items of the set are worn.
+    /// # Examples
+    /// ```
+    /// use game::EquipmentSet;
+    /// let plate = EquipmentSet::plate();
+    /// assert_eq!(plate.active(1).count(), 0);
+    /// assert_eq!(plate.active(2).count(), 1);
+    /// assert_eq!(plate.active(3).count(), 2);
+    /// ```
+    pub fn active(&self, pieces: usize) -> impl Iterator<Item = &SetBonus> {
+        self.bonuses
+            .iter()
+            .filter(move |bonus| bonus.pieces <= pieces)
+    }
+}
+
+#[cfg(test)]
+mod equipment_set_tests {
+    use super::*;
+
+    #[test]
+    fn test_bonuses_add_up() {
+        let plate = EquipmentSet::plate();
+        let total: Stat = plate.active(3).map(|bonus| bonus.stat).sum();
+        assert_eq!(
+            total,
+            Stat {
+                health: 20,
+                attack: 0,
+                defense: 5,
+                magic: 0
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "a set bonus needs at least one piece")]
+    fn test_bonus_without_pieces_is_rejected() {
+        let _ = EquipmentSet::new("Empty").with_bonus(0, Stat::default(), Vec::new());
+    }
+}
@@ -0,0 +1,902 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::game::item::{ConsumableTrait, EquipmentTrait, ItemCategory, ItemError, ItemTrait};
+use crate::game::vitals::VitalsMutTrait;
+
+/// Consumables of the same name sharing one inventory entry.
+///
+/// A stack is never empty, the item on top is used first.
+pub struct ConsumableStack {
+    items: Vec<Box<dyn ConsumableTrait>>,
+}
+
+impl ConsumableStack {
+    fn new(item: Box<dyn ConsumableTrait>) -> Self {
+        Self { items: vec![item] }
+    }
+
+    pub fn name(&self) -> &str {
+        self.top().name()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Always `false`, empty stacks are removed from the inventory.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The item used next.
+    pub fn top(&self) -> &dyn ConsumableTrait {
+        self.items.last().expect("stacks are never empty").as_ref()
+    }
+
+    /// Charges left over all items of the stack, saturating on overflow.
+    pub fn charges(&self) -> u32 {
+        self.items
+            .iter()
+            .fold(0, |charges, item| charges.saturating_add(item.charges()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn ConsumableTrait> {
+        self.items.iter().map(|item| item.as_ref())
+    }
+
+    fn is_full(&self) -> bool {
+        self.len() >= self.top().max_stack()
+    }
+}
+
+/// An entry of an `Inventory`, taking up one slot.
+pub enum InventoryItem {
+    /// Equipment never stacks, every piece has its own entry.
+    Equipment(Box<dyn EquipmentTrait>),
+    Consumable(ConsumableStack),
+}
+
+impl InventoryItem {
+    /// The equipment, or the top of the consumable stack.
+    pub fn item(&self) -> &dyn ItemTrait {
+        match self {
+            InventoryItem::Equipment(item) => item.as_ref(),
+            InventoryItem::Consumable(stack) => stack.top(),
+        }
+    }
+
+    pub fn category(&self) -> ItemCategory {
+        self.item().category()
+    }
+
+    pub fn name(&self) -> &str {
+        self.item().name()
+    }
+
+    pub fn base_name(&self) -> &str {
+        self.item().base_name()
+    }
+
+    /// Whether the entry is called `name` or was rolled from an item called `name`.
+    fn is_called(&self, name: &str) -> bool {
+        self.name() == name || self.base_name() == name
+    }
+
+    /// Number of items in the entry.
+    pub fn count(&self) -> usize {
+        match self {
+            InventoryItem::Equipment(_) => 1,
+            InventoryItem::Consumable(stack) => stack.len(),
+        }
+    }
+
+    /// Weight of all the items in the entry, saturating on overflow.
+    pub fn weight(&self) -> u32 {
+        match self {
+            InventoryItem::Equipment(item) => item.weight(),
+            InventoryItem::Consumable(stack) => stack
+                .iter()
+                .fold(0, |weight, item| weight.saturating_add(item.weight())),
+        }
+    }
+}
+
+/// What happened to an item after `Inventory::use_item`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum UseOutcome {
+    /// The stack is still in the inventory with this many charges in total.
+    Remaining(u32),
+    /// The last charge of the stack was spent and the entry was removed.
+    Consumed,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InventoryError {
+    /// No item at this index.
+    NotFound(usize),
+    /// The item at this index is equipment and can't be used.
+    NotConsumable(usize),
+    Item(ItemError),
+    /// Every slot is taken.
+    NoFreeSlot,
+    /// The total weight would exceed the maximum.
+    TooHeavy {
+        weight: u32,
+        max: u32,
+    },
+    /// The stack at this index can't take that many more items.
+    StackFull(usize),
+    /// The entries at these indices hold different items.
+    Mismatch(usize, usize),
+    /// Can't split this many items off the stack at `index`.
+    InvalidSplit {
+        index: usize,
+        count: usize,
+    },
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InventoryError::NotFound(index) => write!(f, "no item at index {}", index),
+            InventoryError::NotConsumable(index) => {
+                write!(f, "the item at index {} is not consumable", index)
+            }
+            InventoryError::Item(err) => write!(f, "{}", err),
+            InventoryError::NoFreeSlot => write!(f, "the inventory has no free slot"),
+            InventoryError::TooHeavy { weight, max } => {
+                write!(f, "the inventory would weigh {}, more than {}", weight, max)
+            }
+            InventoryError::StackFull(index) => write!(f, "the stack at index {} is full", index),
+            InventoryError::Mismatch(first, second) => {
+                write!(f, "the items at index {} and {} don't stack", first, second)
+            }
+            InventoryError::InvalidSplit { index, count } => {
+                write!(
+                    f,
+                    "can't split {} items off the stack at index {}",
+                    count, index
+                )
+            }
+        }
+    }
+}
+
+impl Error for InventoryError {}
+
+impl From<ItemError> for InventoryError {
+    fn from(err: ItemError) -> Self {
+        InventoryError::Item(err)
+    }
+}
+
+/// An item the `Inventory` refused, handed back along with the reason.
+pub struct AddError<I> {
+    pub item: I,
+    pub error: InventoryError,
+}
+
+impl<I> fmt::Debug for AddError<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AddError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I> fmt::Display for AddError<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl<I> Error for AddError<I> {}
+
+/// Items carried but not worn.
+///
+/// Every entry takes one slot: a piece of equipment or a stack of
+/// consumables sharing a name. Both the number of slots and the total weight
+/// can be limited, an inventory is unlimited by default.
+#[derive(Default)]
+pub struct Inventory {
+    items: Vec<InventoryItem>,
+    max_slots: Option<usize>,
+    max_weight: Option<u32>,
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_slots(mut self, slots: usize) -> Self {
+        self.max_slots = Some(slots);
+        self
+    }
+
+    pub fn with_max_weight(mut self, weight: u32) -> Self {
+        self.max_weight = Some(weight);
+        self
+    }
+
+    pub fn max_slots(&self) -> Option<usize> {
+        self.max_slots
+    }
+
+    pub fn max_weight(&self) -> Option<u32> {
+        self.max_weight
+    }
+
+    /// Total weight of the items carried, saturating on overflow.
+    pub fn weight(&self) -> u32 {
+        self.items
+            .iter()
+            .fold(0, |weight, entry| weight.saturating_add(entry.weight()))
+    }
+
+    /// Add a piece of equipment in a slot of its own, returning its index.
+    pub fn add_equipment<I: EquipmentTrait + 'static>(
+        &mut self,
+        item: I,
+    ) -> Result<usize, AddError<I>> {
+        if let Err(error) = self.check_equipment(&item) {
+            return Err(AddError { item, error });
+        }
+        self.items.push(InventoryItem::Equipment(Box::new(item)));
+        Ok(self.items.len() - 1)
+    }
+
+    /// Like `add_equipment`, for equipment that is already boxed, e.g. `Loot`.
+    pub fn add_boxed_equipment(
+        &mut self,
+        item: Box<dyn EquipmentTrait>,
+    ) -> Result<usize, AddError<Box<dyn EquipmentTrait>>> {
+        if let Err(error) = self.check_equipment(item.as_ref()) {
+            return Err(AddError { item, error });
+        }
+        self.items.push(InventoryItem::Equipment(item));
+        Ok(self.items.len() - 1)
+    }
+
+    /// Add a consumable, on top of the first stack of the same name with room
+    /// left or else in a new slot. Returns the index of its stack.
+    ///
+    /// Depleted items are refused, so the top of a stack can always be used.
+    /// # Examples
+    /// ```
+    /// use game::{BloodBag, Inventory};
+    /// let mut inventory = Inventory::new().with_max_slots(1);
+    /// assert_eq!(inventory.add_consumable(BloodBag::new()).unwrap(), 0);
+    /// assert_eq!(inventory.add_consumable(BloodBag::new()).unwrap(), 0);
+    /// assert_eq!(inventory.get(0).unwrap().count(), 2);
+    /// ```
+    pub fn add_consumable<I: ConsumableTrait + 'static>(
+        &mut self,
+        item: I,
+    ) -> Result<usize, AddError<I>> {
+        match self.check_consumable(&item) {
+            Ok(stack) => Ok(self.push_consumable(Box::new(item), stack)),
+            Err(error) => Err(AddError { item, error }),
+        }
+    }
+
+    /// Like `add_consumable`, for consumables that are already boxed, e.g. `Loot`.
+    pub fn add_boxed_consumable(
+        &mut self,
+        item: Box<dyn ConsumableTrait>,
+    ) -> Result<usize, AddError<Box<dyn ConsumableTrait>>> {
+        match self.check_consumable(item.as_ref()) {
+            Ok(stack) => Ok(self.push_consumable(item, stack)),
+            Err(error) => Err(AddError { item, error }),
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&InventoryItem> {
+        self.items.get(index)
+    }
+
+    /// Take out the whole entry at `index`, later entries move up by one.
+    pub fn remove(&mut self, index: usize) -> Option<InventoryItem> {
+        if index < self.items.len() {
+            Some(self.items.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Move `count` items off the top of the stack at `index` into a new slot,
+    /// returning the index of the new stack.
+    /// # Examples
+    /// ```
+    /// use game::{BloodBag, Inventory};
+    /// let mut inventory = Inventory::new();
+    /// for _ in 0..3 {
+    ///     inventory.add_consumable(BloodBag::new()).unwrap();
+    /// }
+    /// assert_eq!(inventory.split(0, 1), Ok(1));
+    /// assert_eq!(inventory.get(0).unwrap().count(), 2);
+    /// assert_eq!(inventory.get(1).unwrap().count(), 1);
+    /// ```
+    pub fn split(&mut self, index: usize, count: usize) -> Result<usize, InventoryError> {
+        let len = match self.items.get(index) {
+            None => return Err(InventoryError::NotFound(index)),
+            Some(InventoryItem::Equipment(_)) => return Err(InventoryError::NotConsumable(index)),
+            Some(InventoryItem::Consumable(stack)) => stack.len(),
+        };
+        if count == 0 || count >= len {
+            return Err(InventoryError::InvalidSplit { index, count });
+        }
+        self.check_free_slot()?;
+        let Some(InventoryItem::Consumable(stack)) = self.items.get_mut(index) else {
+            unreachable!("checked above");
+        };
+        let items = stack.items.split_off(len - count);
+        self.items
+            .push(InventoryItem::Consumable(ConsumableStack { items }));
+        Ok(self.items.len() - 1)
+    }
+
+    /// Move the whole stack at `from` on top of the stack at `into`.
+    ///
+    /// The entry at `from` is removed, so later entries move up by one.
+    pub fn merge(&mut self, from: usize, into: usize) -> Result<(), InventoryError> {
+        let (source, target) = match (self.items.get(from), self.items.get(into)) {
+            (None, _) => return Err(InventoryError::NotFound(from)),
+            (_, None) => return Err(InventoryError::NotFound(into)),
+            (Some(InventoryItem::Consumable(source)), Some(InventoryItem::Consumable(target))) => {
+                (source, target)
+            }
+            (Some(InventoryItem::Consumable(_)), Some(_)) => {
+                return Err(InventoryError::NotConsumable(into))
+            }
+            (Some(_), _) => return Err(InventoryError::NotConsumable(from)),
+        };
+        if from == into || source.name() != target.name() {
+            return Err(InventoryError::Mismatch(from, into));
+        }
+        if target.len() + source.len() > target.top().max_stack() {
+            return Err(InventoryError::StackFull(into));
+        }
+        let Some(InventoryItem::Consumable(source)) = self.items.get_mut(from) else {
+            unreachable!("checked above");
+        };
+        let mut items = std::mem::take(&mut source.items);
+        if let Some(InventoryItem::Consumable(target)) = self.items.get_mut(into) {
+            target.items.append(&mut items);
+        }
+        self.items.remove(from);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &InventoryItem> {
+        self.items.iter()
+    }
+
+    /// Number of items called `name` or rolled from it, over every entry.
+    /// Depleted consumables aren't counted.
+    pub fn count(&self, name: &str) -> usize {
+        self.items
+            .iter()
+            .filter(|entry| entry.is_called(name))
+            .map(|entry| match entry {
+                InventoryItem::Equipment(_) => 1,
+                InventoryItem::Consumable(stack) => {
+                    stack.iter().filter(|item| !item.is_depleted()).count()
+                }
+            })
+            .sum()
+    }
+
+    /// Take out up to `count` items called `name` or rolled from it, starting
+    /// with the first entry.
+    ///
+    /// Whole entries are removed, a stack holding more than needed gives up
+    /// items from its top.
+    pub fn take(&mut self, name: &str, count: usize) -> Vec<InventoryItem> {
+        let mut taken = Vec::new();
+        let mut needed = count;
+        let mut index = 0;
+        while needed > 0 && index < self.items.len() {
+            let entry = &mut self.items[index];
+            if !entry.is_called(name) {
+                index += 1;
+            } else if entry.count() <= needed {
+                needed -= entry.count();
+                taken.push(self.items.remove(index));
+            } else {
+                if let InventoryItem::Consumable(stack) = entry {
+                    let items = stack.items.split_off(stack.len() - needed);
+                    taken.push(InventoryItem::Consumable(ConsumableStack { items }));
+                }
+                needed = 0;
+            }
+        }
+        taken
+    }
+
+    /// Entries of the given category along with their index.
+    pub fn iter_category(
+        &self,
+        category: ItemCategory,
+    ) -> impl Iterator<Item = (usize, &InventoryItem)> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(move |(_, entry)| entry.category() == category)
+    }
+
+    /// Use the consumable on top of the stack at `index` on the target.
+    ///
+    /// An item is dropped once its last charge is spent, and the entry is
+    /// removed along with the last item of the stack.
+    /// # Examples
+    /// ```
+    /// use game::prelude::*;
+    /// let mut target = Character::new("Arthur", Knight::new());
+    /// target.set_vitals(Vitals {health: 10, mana: 0});
+    /// let mut inventory = Inventory::new();
+    /// inventory.add_consumable(BloodBag::new()).unwrap();
+    /// assert_eq!(inventory.use_item(0, &mut target), Ok(UseOutcome::Consumed));
+    /// assert!(inventory.is_empty());
+    /// assert_eq!(target.get_vitals().health, 40);
+    /// ```
+    pub fn use_item(
+        &mut self,
+        index: usize,
+        target: &mut dyn VitalsMutTrait,
+    ) -> Result<UseOutcome, InventoryError> {
+        let stack = match self.items.get_mut(index) {
+            None => return Err(InventoryError::NotFound(index)),
+            Some(InventoryItem::Equipment(_)) => return Err(InventoryError::NotConsumable(index)),
+            Some(InventoryItem::Consumable(stack)) => stack,
+        };
+        let top = stack.items.last_mut().expect("stacks are never empty");
+        if top.consume(target)? == 0 {
+            stack.items.pop();
+        }
+        if stack.is_empty() {
+            self.items.remove(index);
+            return Ok(UseOutcome::Consumed);
+        }
+        Ok(UseOutcome::Remaining(stack.charges()))
+    }
+
+    fn check_equipment(&self, item: &dyn EquipmentTrait) -> Result<(), InventoryError> {
+        self.check_weight(item.weight()).and(self.check_free_slot())
+    }
+
+    /// The index of the stack the consumable goes on, `None` for a new slot.
+    fn check_consumable(
+        &self,
+        item: &dyn ConsumableTrait,
+    ) -> Result<Option<usize>, InventoryError> {
+        if item.is_depleted() {
+            return Err(InventoryError::Item(ItemError::Depleted));
+        }
+        self.check_weight(item.weight())?;
+        let stack = self.items.iter().position(|entry| match entry {
+            InventoryItem::Consumable(stack) => stack.name() == item.name() && !stack.is_full(),
+            InventoryItem::Equipment(_) => false,
+        });
+        if stack.is_none() {
+            self.check_free_slot()?;
+        }
+        Ok(stack)
+    }
+
+    fn push_consumable(&mut self, item: Box<dyn ConsumableTrait>, stack: Option<usize>) -> usize {
+        match stack.map(|index| (index, &mut self.items[index])) {
+            Some((index, InventoryItem::Consumable(stack))) => {
+                stack.items.push(item);
+                index
+            }
+            _ => {
+                self.items
+                    .push(InventoryItem::Consumable(ConsumableStack::new(item)));
+                self.items.len() - 1
+            }
+        }
+    }
+
+    fn check_free_slot(&self) -> Result<(), InventoryError> {
+        match self.max_slots {
+            Some(max) if self.items.len() >= max => Err(InventoryError::NoFreeSlot),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_weight(&self, extra: u32) -> Result<(), InventoryError> {
+        let weight = self.weight().saturating_add(extra);
+        match self.max_weight {
+            Some(max) if weight > max => Err(InventoryError::TooHeavy { weight, max }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod inventory_tests {
+    use super::*;
+    use crate::game::item::{BloodBag, Helmet, StrengthPotion, Sword};
+    use crate::game::loadout::EquipmentSlot;
+    use crate::game::stat::{Stat, StatMutTrait, StatTrait};
+    use crate::game::vitals::{Vitals, VitalsTrait};
+
+    struct MockTarget {
+        vitals: Vitals,
+    }
+
+    impl MockTarget {
+        fn new(health: i32) -> Self {
+            Self {
+                vitals: Vitals { health, mana: 0 },
+            }
+        }
+    }
+
+    impl StatTrait for MockTarget {
+        fn get_stat(&self) -> Stat {
+            Stat {
+                health: 100,
+                attack: 0,
+                defense: 0,
+                magic: 0,
+            }
+        }
+    }
+
+    impl StatMutTrait for MockTarget {
+        fn set_stat(&mut self, _stat: Stat) {}
+    }
+
+    impl VitalsTrait for MockTarget {
+        fn get_vitals(&self) -> Vitals {
+            self.vitals
+        }
+    }
+
+    impl VitalsMutTrait for MockTarget {
+        fn set_vitals(&mut self, vitals: Vitals) {
+            self.vitals = vitals;
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_items() {
+        let mut inventory = Inventory::new();
+        inventory.add_equipment(Helmet::new()).unwrap();
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        assert_eq!(inventory.len(), 2);
+        assert_eq!(
+            inventory.get(0).unwrap().category(),
+            ItemCategory::Equipment
+        );
+        assert_eq!(
+            inventory.get(1).unwrap().category(),
+            ItemCategory::Consumable
+        );
+        assert!(matches!(
+            inventory.remove(0),
+            Some(InventoryItem::Equipment(_))
+        ));
+        assert!(inventory.remove(1).is_none());
+        assert_eq!(inventory.len(), 1);
+    }
+
+    #[test]
+    fn test_consumables_stack_by_name() {
+        let mut inventory = Inventory::new();
+        assert_eq!(inventory.add_consumable(BloodBag::new()).unwrap(), 0);
+        assert_eq!(inventory.add_consumable(StrengthPotion::new()).unwrap(), 1);
+        assert_eq!(
+            inventory.add_consumable(BloodBag::with_charges(2)).unwrap(),
+            0
+        );
+        let InventoryItem::Consumable(stack) = inventory.get(0).unwrap() else {
+            panic!("expected a stack");
+        };
+        assert_eq!(stack.name(), "Blood Bag");
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.charges(), 3);
+        assert_eq!(inventory.len(), 2);
+    }
+
+    #[test]
+    fn test_full_stack_starts_a_new_one() {
+        let mut inventory = Inventory::new();
+        for _ in 0..11 {
+            inventory.add_consumable(BloodBag::new()).unwrap();
+        }
+        assert_eq!(inventory.len(), 2);
+        assert_eq!(inventory.get(0).unwrap().count(), 10);
+        assert_eq!(inventory.get(1).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_equipment_never_stacks() {
+        let mut inventory = Inventory::new();
+        assert_eq!(inventory.add_equipment(Sword::new()).unwrap(), 0);
+        assert_eq!(inventory.add_equipment(Sword::new()).unwrap(), 1);
+        assert_eq!(inventory.get(1).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_slot_capacity() {
+        let mut inventory = Inventory::new().with_max_slots(1);
+        inventory.add_equipment(Helmet::new()).unwrap();
+        let err = inventory.add_equipment(Sword::new()).unwrap_err();
+        assert_eq!(err.error, InventoryError::NoFreeSlot);
+        assert_eq!(err.item.name(), "Sword");
+        let err = inventory.add_consumable(BloodBag::new()).unwrap_err();
+        assert_eq!(err.error, InventoryError::NoFreeSlot);
+        assert_eq!(inventory.len(), 1);
+    }
+
+    #[test]
+    fn test_weight_capacity() {
+        let mut inventory = Inventory::new().with_max_weight(10);
+        inventory.add_equipment(Sword::new()).unwrap();
+        inventory.add_equipment(Helmet::new()).unwrap();
+        assert_eq!(inventory.weight(), 9);
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        let err = inventory.add_consumable(BloodBag::new()).unwrap_err();
+        assert_eq!(
+            err.error,
+            InventoryError::TooHeavy {
+                weight: 11,
+                max: 10
+            }
+        );
+        assert_eq!(inventory.weight(), 10);
+    }
+
+    struct Anvil;
+
+    impl ItemTrait for Anvil {
+        fn name(&self) -> &str {
+            "Anvil"
+        }
+
+        fn stat(&self) -> Stat {
+            Stat::default()
+        }
+
+        fn category(&self) -> ItemCategory {
+            ItemCategory::Equipment
+        }
+
+        fn weight(&self) -> u32 {
+            u32::MAX
+        }
+    }
+
+    impl EquipmentTrait for Anvil {
+        fn slot(&self) -> EquipmentSlot {
+            EquipmentSlot::OffHand
+        }
+    }
+
+    #[test]
+    fn test_weight_and_charges_saturate() {
+        let mut inventory = Inventory::new();
+        inventory.add_equipment(Anvil).unwrap();
+        inventory.add_equipment(Anvil).unwrap();
+        assert_eq!(inventory.weight(), u32::MAX);
+        inventory
+            .add_consumable(BloodBag::with_charges(u32::MAX))
+            .unwrap();
+        inventory
+            .add_consumable(BloodBag::with_charges(u32::MAX))
+            .unwrap();
+        let Some(InventoryItem::Consumable(stack)) = inventory.get(2) else {
+            panic!("expected a stack");
+        };
+        assert_eq!(stack.charges(), u32::MAX);
+    }
+
+    #[test]
+    fn test_split() {
+        let mut inventory = Inventory::new();
+        for _ in 0..4 {
+            inventory.add_consumable(BloodBag::new()).unwrap();
+        }
+        assert_eq!(inventory.split(0, 3), Ok(1));
+        assert_eq!(inventory.get(0).unwrap().count(), 1);
+        assert_eq!(inventory.get(1).unwrap().count(), 3);
+    }
+
+    #[test]
+    fn test_split_errors() {
+        let mut inventory = Inventory::new().with_max_slots(2);
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        inventory.add_equipment(Sword::new()).unwrap();
+        assert_eq!(
+            inventory.split(0, 0),
+            Err(InventoryError::InvalidSplit { index: 0, count: 0 })
+        );
+        assert_eq!(
+            inventory.split(0, 2),
+            Err(InventoryError::InvalidSplit { index: 0, count: 2 })
+        );
+        assert_eq!(inventory.split(1, 1), Err(InventoryError::NotConsumable(1)));
+        assert_eq!(inventory.split(5, 1), Err(InventoryError::NotFound(5)));
+        assert_eq!(inventory.split(0, 1), Err(InventoryError::NoFreeSlot));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut inventory = Inventory::new();
+        for _ in 0..3 {
+            inventory.add_consumable(BloodBag::new()).unwrap();
+        }
+        inventory.split(0, 1).unwrap();
+        inventory.add_equipment(Sword::new()).unwrap();
+        assert_eq!(inventory.merge(1, 0), Ok(()));
+        assert_eq!(inventory.len(), 2);
+        assert_eq!(inventory.get(0).unwrap().count(), 3);
+    }
+
+    #[test]
+    fn test_merge_errors() {
+        let mut inventory = Inventory::new();
+        for _ in 0..10 {
+            inventory.add_consumable(BloodBag::new()).unwrap();
+        }
+        inventory.split(0, 1).unwrap();
+        inventory.add_consumable(StrengthPotion::new()).unwrap();
+        inventory.add_equipment(Sword::new()).unwrap();
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        assert_eq!(inventory.len(), 4);
+        assert_eq!(inventory.merge(1, 0), Err(InventoryError::StackFull(0)));
+        assert_eq!(inventory.merge(2, 1), Err(InventoryError::Mismatch(2, 1)));
+        assert_eq!(inventory.merge(1, 1), Err(InventoryError::Mismatch(1, 1)));
+        assert_eq!(inventory.merge(3, 1), Err(InventoryError::NotConsumable(3)));
+        assert_eq!(inventory.merge(1, 3), Err(InventoryError::NotConsumable(3)));
+        assert_eq!(inventory.merge(1, 9), Err(InventoryError::NotFound(9)));
+        assert_eq!(inventory.len(), 4);
+    }
+
+    #[test]
+    fn test_iter_category() {
+        let mut inventory = Inventory::new();
+        inventory.add_equipment(Helmet::new()).unwrap();
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        inventory.add_equipment(Sword::new()).unwrap();
+        let equipment: Vec<_> = inventory
+            .iter_category(ItemCategory::Equipment)
+            .map(|(index, entry)| (index, entry.name()))
+            .collect();
+        assert_eq!(equipment, vec![(0, "Helmet"), (2, "Sword")]);
+        assert_eq!(inventory.iter_category(ItemCategory::Consumable).count(), 1);
+    }
+
+    #[test]
+    fn test_count_and_take() {
+        let mut inventory = Inventory::new();
+        for _ in 0..3 {
+            inventory.add_consumable(BloodBag::new()).unwrap();
+        }
+        inventory.split(0, 1).unwrap();
+        inventory.add_equipment(Sword::new()).unwrap();
+        assert_eq!(inventory.count("Blood Bag"), 3);
+        assert_eq!(inventory.count("Sword"), 1);
+        let taken = inventory.take("Blood Bag", 2);
+        assert_eq!(taken.iter().map(InventoryItem::count).sum::<usize>(), 2);
+        assert_eq!(inventory.count("Blood Bag"), 1);
+        assert_eq!(inventory.take("Blood Bag", 5).len(), 1);
+        assert_eq!(inventory.count("Blood Bag"), 0);
+        assert_eq!(inventory.len(), 1);
+    }
+
+    #[test]
+    fn test_use_item_keeps_item_with_charges_left() {
+        let mut inventory = Inventory::new();
+        inventory.add_consumable(BloodBag::with_charges(2)).unwrap();
+        let mut target = MockTarget::new(10);
+        assert_eq!(
+            inventory.use_item(0, &mut target),
+            Ok(UseOutcome::Remaining(1))
+        );
+        assert_eq!(inventory.len(), 1);
+        assert_eq!(inventory.use_item(0, &mut target), Ok(UseOutcome::Consumed));
+        assert!(inventory.is_empty());
+        assert_eq!(target.get_vitals().health, 70);
+    }
+
+    #[test]
+    fn test_use_item_goes_through_the_stack() {
+        let mut inventory = Inventory::new();
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        let mut target = MockTarget::new(10);
+        assert_eq!(
+            inventory.use_item(0, &mut target),
+            Ok(UseOutcome::Remaining(1))
+        );
+        assert_eq!(inventory.get(0).unwrap().count(), 1);
+        assert_eq!(inventory.use_item(0, &mut target), Ok(UseOutcome::Consumed));
+        assert!(inventory.is_empty());
+    }
+
+    #[test]
+    fn test_use_item_rejects_equipment() {
+        let mut inventory = Inventory::new();
+        inventory.add_equipment(Sword::new()).unwrap();
+        let mut target = MockTarget::new(10);
+        assert_eq!(
+            inventory.use_item(0, &mut target),
+            Err(InventoryError::NotConsumable(0))
+        );
+        assert_eq!(inventory.len(), 1);
+    }
+
+    #[test]
+    fn test_use_item_missing_index() {
+        let mut inventory = Inventory::new();
+        let mut target = MockTarget::new(10);
+        assert_eq!(
+            inventory.use_item(3, &mut target),
+            Err(InventoryError::NotFound(3))
+        );
+    }
+
+    #[test]
+    fn test_add_boxed_items() {
+        let mut inventory = Inventory::new().with_max_slots(2);
+        assert_eq!(
+            inventory
+                .add_boxed_equipment(Box::new(Sword::new()))
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            inventory
+                .add_boxed_consumable(Box::new(BloodBag::new()))
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            inventory
+                .add_boxed_consumable(Box::new(BloodBag::new()))
+                .unwrap(),
+            1
+        );
+        let err = inventory
+            .add_boxed_equipment(Box::new(Helmet::new()))
+            .unwrap_err();
+        assert_eq!(err.error, InventoryError::NoFreeSlot);
+        assert_eq!(err.item.name(), "Helmet");
+        assert_eq!(inventory.count("Blood Bag"), 2);
+    }
+
+    #[test]
+    fn test_add_rejects_depleted_consumable() {
+        let mut inventory = Inventory::new();
+        let err = inventory
+            .add_consumable(BloodBag::with_charges(0))
+            .unwrap_err();
+        assert_eq!(err.error, InventoryError::Item(ItemError::Depleted));
+        assert!(inventory.is_empty());
+    }
+
+    #[test]
+    fn test_depleted_item_never_blocks_a_stack() {
+        let mut inventory = Inventory::new();
+        inventory.add_consumable(BloodBag::new()).unwrap();
+        assert!(inventory.add_consumable(BloodBag::with_charges(0)).is_err());
+        let mut target = MockTarget::new(10);
+        assert_eq!(inventory.use_item(0, &mut target), Ok(UseOutcome::Consumed));
+        assert_eq!(target.get_vitals().health, 40);
+    }
+}
@@ -1,7 +1,124 @@
-use crate::game::stat::{Stat, StatTrait};
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
 
-pub trait ItemTrait {
-    fn create_effect<T: StatTrait>(&self, target: &T) -> ();
+use crate::game::loadout::EquipmentSlot;
+use crate::game::modifier::{apply_modifiers, StatModifier};
+use crate::game::stat::{OverflowPolicy, Stat, StatError, StatField};
+use crate::game::status::StatusEffect;
+use crate::game::vitals::VitalsMutTrait;
+
+/// Whether an item is worn or used up.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ItemCategory {
+    /// Applies while worn, see `EquipmentTrait`.
+    Equipment,
+    /// Applies once per use and disappears when out of charges, see `ConsumableTrait`.
+    Consumable,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ItemError {
+    /// The consumable has no charges left.
+    Depleted,
+}
+
+impl fmt::Display for ItemError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ItemError::Depleted => write!(f, "the item has no charges left"),
+        }
+    }
+}
+
+impl Error for ItemError {}
+
+/// Items are plain data shared across threads, hence the `Send + Sync` bound.
+pub trait ItemTrait: Send + Sync {
+    /// Display name, consumables with the same name stack in an `Inventory`.
+    fn name(&self) -> &str;
+
+    /// Name of the plain item the entry was made from, e.g. `Helmet` for a
+    /// rolled `Sturdy Helmet`. Crafting matches ingredients on it.
+    fn base_name(&self) -> &str {
+        self.name()
+    }
+
+    fn stat(&self) -> Stat;
+    fn category(&self) -> ItemCategory;
+
+    /// Weight counted against the capacity of an `Inventory`.
+    fn weight(&self) -> u32 {
+        1
+    }
+
+    /// Percentage or extra flat modifiers applied on top of the flat `stat`.
+    fn modifiers(&self) -> &[StatModifier] {
+        &[]
+    }
+
+    /// Add the item `Stat` to the target, saturating on overflow.
+    ///
+    /// The effect is baked into the target and can't be taken back, use
+    /// `Equipped` for gear that can be removed again.
+    fn create_effect<T: VitalsMutTrait + ?Sized>(&self, target: &mut T)
+    where
+        Self: Sized,
+    {
+        // The default policy never fails.
+        let _ = self.create_effect_with(target, OverflowPolicy::default());
+    }
+
+    /// Add the item `Stat` to the target following the given `OverflowPolicy`,
+    /// then apply the item modifiers to the result.
+    /// The current vitals are kept within the new maximums, and the target is
+    /// left untouched when the policy reports an error.
+    fn create_effect_with<T: VitalsMutTrait + ?Sized>(
+        &self,
+        target: &mut T,
+        policy: OverflowPolicy,
+    ) -> Result<(), StatError>
+    where
+        Self: Sized,
+    {
+        let stat = policy.add(target.get_stat(), self.stat())?;
+        let stat = apply_modifiers(stat, self.modifiers());
+        target.set_stat(stat);
+        target.set_vitals(target.get_vitals().clamp_to(&stat));
+        Ok(())
+    }
+}
+
+/// An item worn in an `EquipmentSlot`.
+pub trait EquipmentTrait: ItemTrait {
+    fn slot(&self) -> EquipmentSlot;
+
+    /// Name of the `EquipmentSet` the item belongs to, if any.
+    fn set(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// An item used up by applying its effect, once per charge.
+pub trait ConsumableTrait: ItemTrait {
+    fn charges(&self) -> u32;
+
+    /// Apply the effect once and spend a charge, returning the charges left.
+    fn consume(&mut self, target: &mut dyn VitalsMutTrait) -> Result<u32, ItemError>;
+
+    fn is_depleted(&self) -> bool {
+        self.charges() == 0
+    }
+
+    /// How many of this item fit in one `Inventory` stack.
+    fn max_stack(&self) -> usize {
+        10
+    }
+
+    /// Status effect granted on every use, applied by `Character::use_item`.
+    fn status(&self) -> Option<StatusEffect> {
+        None
+    }
 }
 
 pub struct Helmet {
@@ -21,9 +138,37 @@ impl Helmet {
     }
 }
 
+impl Default for Helmet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ItemTrait for Helmet {
-    fn create_effect<T: StatTrait>(&self, target: &T) -> () {
-        target.set_stat(self.stat + target.get_stat());
+    fn name(&self) -> &str {
+        "Helmet"
+    }
+
+    fn stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn category(&self) -> ItemCategory {
+        ItemCategory::Equipment
+    }
+
+    fn weight(&self) -> u32 {
+        3
+    }
+}
+
+impl EquipmentTrait for Helmet {
+    fn slot(&self) -> EquipmentSlot {
+        EquipmentSlot::Head
+    }
+
+    fn set(&self) -> Option<&str> {
+        Some("Plate")
     }
 }
 
@@ -44,9 +189,37 @@ impl ChestPlate {
     }
 }
 
+impl Default for ChestPlate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ItemTrait for ChestPlate {
-    fn create_effect<T: StatTrait>(&self, target: &T) -> () {
-        target.set_stat(self.stat + target.get_stat());
+    fn name(&self) -> &str {
+        "Chest Plate"
+    }
+
+    fn stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn category(&self) -> ItemCategory {
+        ItemCategory::Equipment
+    }
+
+    fn weight(&self) -> u32 {
+        8
+    }
+}
+
+impl EquipmentTrait for ChestPlate {
+    fn slot(&self) -> EquipmentSlot {
+        EquipmentSlot::Chest
+    }
+
+    fn set(&self) -> Option<&str> {
+        Some("Plate")
     }
 }
 
@@ -67,9 +240,37 @@ impl Leggings {
     }
 }
 
+impl Default for Leggings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ItemTrait for Leggings {
-    fn create_effect<T: StatTrait>(&self, target: &T) -> () {
-        target.set_stat(self.stat + target.get_stat());
+    fn name(&self) -> &str {
+        "Leggings"
+    }
+
+    fn stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn category(&self) -> ItemCategory {
+        ItemCategory::Equipment
+    }
+
+    fn weight(&self) -> u32 {
+        5
+    }
+}
+
+impl EquipmentTrait for Leggings {
+    fn slot(&self) -> EquipmentSlot {
+        EquipmentSlot::Legs
+    }
+
+    fn set(&self) -> Option<&str> {
+        Some("Plate")
     }
 }
 
@@ -90,24 +291,47 @@ impl Sword {
     }
 }
 
+impl Default for Sword {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ItemTrait for Sword {
-    fn create_effect<T: StatTrait>(&self, target: &T) -> () {
-        let stat = Stat {
-            health: 0,
-            attack: 15,
-            defense: 4,
-            magic: 0,
-        };
-        target.set_stat(stat + target.get_stat());
+    fn name(&self) -> &str {
+        "Sword"
+    }
+
+    fn stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn category(&self) -> ItemCategory {
+        ItemCategory::Equipment
+    }
+
+    fn weight(&self) -> u32 {
+        6
+    }
+}
+
+impl EquipmentTrait for Sword {
+    fn slot(&self) -> EquipmentSlot {
+        EquipmentSlot::MainHand
     }
 }
 
 pub struct BloodBag {
     stat: Stat,
+    charges: u32,
 }
 
 impl BloodBag {
     pub fn new() -> Self {
+        Self::with_charges(1)
+    }
+
+    pub fn with_charges(charges: u32) -> Self {
         Self {
             stat: Stat {
                 health: 30,
@@ -115,13 +339,121 @@ impl BloodBag {
                 defense: 0,
                 magic: 0,
             },
+            charges,
         }
     }
 }
 
+impl Default for BloodBag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ItemTrait for BloodBag {
-    fn create_effect<T: StatTrait>(&self, target: &T) -> () {
-        target.set_stat(self.stat + target.get_stat());
+    fn name(&self) -> &str {
+        "Blood Bag"
+    }
+
+    fn stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn category(&self) -> ItemCategory {
+        ItemCategory::Consumable
+    }
+
+    /// Heal the target by the bag's health, capped at the target's maximum health.
+    fn create_effect_with<T: VitalsMutTrait + ?Sized>(
+        &self,
+        target: &mut T,
+        _policy: OverflowPolicy,
+    ) -> Result<(), StatError> {
+        let stat = target.get_stat();
+        target.set_vitals(target.get_vitals().heal(self.stat.health, &stat));
+        Ok(())
+    }
+}
+
+impl ConsumableTrait for BloodBag {
+    fn charges(&self) -> u32 {
+        self.charges
+    }
+
+    fn consume(&mut self, target: &mut dyn VitalsMutTrait) -> Result<u32, ItemError> {
+        if self.charges == 0 {
+            return Err(ItemError::Depleted);
+        }
+        self.create_effect(target);
+        self.charges -= 1;
+        Ok(self.charges)
+    }
+}
+
+/// Grants +20 attack for 3 turns, the base stat is left untouched.
+pub struct StrengthPotion {
+    stat: Stat,
+    charges: u32,
+}
+
+impl StrengthPotion {
+    pub fn new() -> Self {
+        Self {
+            stat: Stat {
+                health: 0,
+                attack: 20,
+                defense: 0,
+                magic: 0,
+            },
+            charges: 1,
+        }
+    }
+}
+
+impl Default for StrengthPotion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ItemTrait for StrengthPotion {
+    fn name(&self) -> &str {
+        "Strength Potion"
+    }
+
+    fn stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn category(&self) -> ItemCategory {
+        ItemCategory::Consumable
+    }
+
+    /// The potion only works through its `status`, there is no lasting effect.
+    fn create_effect_with<T: VitalsMutTrait + ?Sized>(
+        &self,
+        _target: &mut T,
+        _policy: OverflowPolicy,
+    ) -> Result<(), StatError> {
+        Ok(())
+    }
+}
+
+impl ConsumableTrait for StrengthPotion {
+    fn charges(&self) -> u32 {
+        self.charges
+    }
+
+    fn consume(&mut self, _target: &mut dyn VitalsMutTrait) -> Result<u32, ItemError> {
+        if self.charges == 0 {
+            return Err(ItemError::Depleted);
+        }
+        self.charges -= 1;
+        Ok(self.charges)
+    }
+
+    fn status(&self) -> Option<StatusEffect> {
+        Some(StatusEffect::stat_change("Strength", self.stat, 3))
     }
 }
 
@@ -142,15 +474,82 @@ impl Wand {
     }
 }
 
+impl Default for Wand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ItemTrait for Wand {
-    fn create_effect<T: StatTrait>(&self, target: &T) -> () {
-        let stat = Stat {
-            health: 0,
-            attack: 0,
-            defense: 0,
-            magic: 70,
-        };
-        target.set_stat(stat + target.get_stat());
+    fn name(&self) -> &str {
+        "Wand"
+    }
+
+    fn stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn category(&self) -> ItemCategory {
+        ItemCategory::Equipment
+    }
+
+    fn weight(&self) -> u32 {
+        2
+    }
+}
+
+impl EquipmentTrait for Wand {
+    fn slot(&self) -> EquipmentSlot {
+        EquipmentSlot::MainHand
+    }
+}
+
+pub struct Ring {
+    stat: Stat,
+    modifiers: Vec<StatModifier>,
+}
+
+impl Ring {
+    pub fn new() -> Self {
+        Self {
+            stat: Stat {
+                health: 0,
+                attack: 0,
+                defense: 0,
+                magic: 0,
+            },
+            modifiers: vec![StatModifier::add_percent(StatField::Defense, 10.0)],
+        }
+    }
+}
+
+impl Default for Ring {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ItemTrait for Ring {
+    fn name(&self) -> &str {
+        "Ring"
+    }
+
+    fn stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn category(&self) -> ItemCategory {
+        ItemCategory::Equipment
+    }
+
+    fn modifiers(&self) -> &[StatModifier] {
+        &self.modifiers
+    }
+}
+
+impl EquipmentTrait for Ring {
+    fn slot(&self) -> EquipmentSlot {
+        EquipmentSlot::Finger
     }
 }
 
@@ -218,6 +617,7 @@ mod test_item {
     #[test]
     fn test_new_blood_bag() {
         let blood_bag = BloodBag::new();
+        assert_eq!(blood_bag.charges(), 1);
         assert_eq!(
             blood_bag.stat,
             Stat {
@@ -243,41 +643,86 @@ mod test_item {
         );
     }
 
+    use crate::game::stat::{Interior, InteriorStatTrait, StatMutTrait, StatTrait};
+    use crate::game::vitals::{InteriorVitalsTrait, Vitals, VitalsTrait};
     use std::cell::Cell;
 
     struct MockStat {
-        stat: Cell<Stat>,
+        stat: Stat,
+        vitals: Vitals,
     }
 
     impl MockStat {
         fn new(stat: Stat) -> Self {
             Self {
-                stat: Cell::new(stat),
+                stat,
+                vitals: Vitals::full(&stat),
             }
         }
     }
 
     impl StatTrait for MockStat {
+        fn get_stat(&self) -> Stat {
+            self.stat
+        }
+    }
+
+    impl StatMutTrait for MockStat {
+        fn set_stat(&mut self, stat: Stat) {
+            self.stat = stat;
+        }
+    }
+
+    impl VitalsTrait for MockStat {
+        fn get_vitals(&self) -> Vitals {
+            self.vitals
+        }
+    }
+
+    impl VitalsMutTrait for MockStat {
+        fn set_vitals(&mut self, vitals: Vitals) {
+            self.vitals = vitals;
+        }
+    }
+
+    struct MockCellStat {
+        stat: Cell<Stat>,
+        vitals: Cell<Vitals>,
+    }
+
+    impl StatTrait for MockCellStat {
         fn get_stat(&self) -> Stat {
             self.stat.get()
         }
+    }
 
-        fn set_stat(&self, stat: Stat) -> () {
+    impl InteriorStatTrait for MockCellStat {
+        fn set_stat(&self, stat: Stat) {
             self.stat.set(stat);
         }
     }
 
+    impl VitalsTrait for MockCellStat {
+        fn get_vitals(&self) -> Vitals {
+            self.vitals.get()
+        }
+    }
+
+    impl InteriorVitalsTrait for MockCellStat {
+        fn set_vitals(&self, vitals: Vitals) {
+            self.vitals.set(vitals);
+        }
+    }
+
     #[test]
     fn test_helmet_create_effect() {
         let helmet = Helmet::new();
-        let mut mock_stat = MockStat::new(
-            Stat {
-                health: 0,
-                attack: 0,
-                defense: 0,
-                magic: 0,
-            },
-        );
+        let mut mock_stat = MockStat::new(Stat {
+            health: 0,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        });
         helmet.create_effect(&mut mock_stat);
         assert_eq!(
             mock_stat.get_stat(),
@@ -293,14 +738,12 @@ mod test_item {
     #[test]
     fn test_chestplate_create_effect() {
         let chestplate = ChestPlate::new();
-        let mut mock_stat = MockStat::new(
-            Stat {
-                health: 0,
-                attack: 0,
-                defense: 0,
-                magic: 0,
-            },
-        );
+        let mut mock_stat = MockStat::new(Stat {
+            health: 0,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        });
         chestplate.create_effect(&mut mock_stat);
         assert_eq!(
             mock_stat.get_stat(),
@@ -316,14 +759,12 @@ mod test_item {
     #[test]
     fn test_leggings_create_effect() {
         let leggings = Leggings::new();
-        let mut mock_stat = MockStat::new(
-            Stat {
-                health: 0,
-                attack: 0,
-                defense: 0,
-                magic: 0,
-            },
-        );
+        let mut mock_stat = MockStat::new(Stat {
+            health: 0,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        });
         leggings.create_effect(&mut mock_stat);
         assert_eq!(
             mock_stat.get_stat(),
@@ -339,14 +780,12 @@ mod test_item {
     #[test]
     fn test_sword_create_effect() {
         let sword = Sword::new();
-        let mut mock_stat = MockStat::new(
-            Stat {
-                health: 0,
-                attack: 0,
-                defense: 0,
-                magic: 0,
-            },
-        );
+        let mut mock_stat = MockStat::new(Stat {
+            health: 0,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        });
         sword.create_effect(&mut mock_stat);
         assert_eq!(
             mock_stat.get_stat(),
@@ -362,47 +801,233 @@ mod test_item {
     #[test]
     fn test_blood_bag_create_effect() {
         let blood_bag = BloodBag::new();
-        let mut mock_stat = MockStat::new(
+        let stat = Stat {
+            health: 100,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        };
+        let mut mock_stat = MockStat::new(stat);
+        mock_stat.set_vitals(Vitals {
+            health: 50,
+            mana: 0,
+        });
+        blood_bag.create_effect(&mut mock_stat);
+        assert_eq!(mock_stat.get_stat(), stat);
+        assert_eq!(
+            mock_stat.get_vitals(),
+            Vitals {
+                health: 80,
+                mana: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_blood_bag_heal_caps_at_max_health() {
+        let blood_bag = BloodBag::new();
+        let mut mock_stat = MockStat::new(Stat {
+            health: 100,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        });
+        mock_stat.set_vitals(Vitals {
+            health: 90,
+            mana: 0,
+        });
+        blood_bag.create_effect(&mut mock_stat);
+        blood_bag.create_effect(&mut mock_stat);
+        assert_eq!(
+            mock_stat.get_vitals(),
+            Vitals {
+                health: 100,
+                mana: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_blood_bag_consume_spends_charges() {
+        let mut blood_bag = BloodBag::with_charges(2);
+        let mut mock_stat = MockStat::new(Stat {
+            health: 100,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        });
+        mock_stat.set_vitals(Vitals {
+            health: 10,
+            mana: 0,
+        });
+        assert_eq!(blood_bag.consume(&mut mock_stat), Ok(1));
+        assert_eq!(blood_bag.consume(&mut mock_stat), Ok(0));
+        assert!(blood_bag.is_depleted());
+        assert_eq!(blood_bag.consume(&mut mock_stat), Err(ItemError::Depleted));
+        assert_eq!(
+            mock_stat.get_vitals(),
+            Vitals {
+                health: 70,
+                mana: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_create_effect_through_interior_adapter() {
+        let target = MockCellStat {
+            stat: Cell::new(Stat::default()),
+            vitals: Cell::new(Vitals { health: 0, mana: 0 }),
+        };
+        Sword::new().create_effect(&mut Interior(&target));
+        assert_eq!(target.get_stat(), Sword::new().stat());
+    }
+
+    #[test]
+    fn test_item_categories() {
+        assert_eq!(Helmet::new().category(), ItemCategory::Equipment);
+        assert_eq!(ChestPlate::new().category(), ItemCategory::Equipment);
+        assert_eq!(Leggings::new().category(), ItemCategory::Equipment);
+        assert_eq!(Sword::new().category(), ItemCategory::Equipment);
+        assert_eq!(Wand::new().category(), ItemCategory::Equipment);
+        assert_eq!(Ring::new().category(), ItemCategory::Equipment);
+        assert_eq!(BloodBag::new().category(), ItemCategory::Consumable);
+    }
+
+    #[test]
+    fn test_equipment_raises_max_health() {
+        let mut helmet = Helmet::new();
+        helmet.stat.health = 20;
+        let blood_bag = BloodBag::new();
+        let mut mock_stat = MockStat::new(Stat {
+            health: 100,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        });
+        helmet.create_effect(&mut mock_stat);
+        assert_eq!(
+            mock_stat.get_vitals(),
+            Vitals {
+                health: 100,
+                mana: 0
+            }
+        );
+        blood_bag.create_effect(&mut mock_stat);
+        assert_eq!(
+            mock_stat.get_vitals(),
+            Vitals {
+                health: 120,
+                mana: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_wand_create_effect() {
+        let wand = Wand::new();
+        let mut mock_stat = MockStat::new(Stat {
+            health: 0,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        });
+        wand.create_effect(&mut mock_stat);
+        assert_eq!(
+            mock_stat.get_stat(),
             Stat {
                 health: 0,
                 attack: 0,
                 defense: 0,
-                magic: 0,
-            },
+                magic: 70
+            }
         );
-        blood_bag.create_effect(&mut mock_stat);
+    }
+
+    #[test]
+    fn test_new_ring() {
+        let ring = Ring::new();
         assert_eq!(
-            mock_stat.get_stat(),
+            ring.stat,
             Stat {
-                health: 30,
+                health: 0,
                 attack: 0,
                 defense: 0,
                 magic: 0
             }
         );
+        assert_eq!(
+            ring.modifiers(),
+            &[StatModifier::add_percent(StatField::Defense, 10.0)]
+        );
     }
 
     #[test]
-    fn test_wand_create_effect() {
-        let wand = Wand::new();
-        let mut mock_stat = MockStat::new(
+    fn test_ring_create_effect() {
+        let ring = Ring::new();
+        let mut mock_stat = MockStat::new(Stat {
+            health: 0,
+            attack: 0,
+            defense: 50,
+            magic: 0,
+        });
+        ring.create_effect(&mut mock_stat);
+        assert_eq!(
+            mock_stat.get_stat(),
             Stat {
                 health: 0,
                 attack: 0,
-                defense: 0,
-                magic: 0,
-            },
+                defense: 55,
+                magic: 0
+            }
         );
-        wand.create_effect(&mut mock_stat);
+    }
+
+    #[test]
+    fn test_equipment_slots() {
+        assert_eq!(Helmet::new().slot(), EquipmentSlot::Head);
+        assert_eq!(ChestPlate::new().slot(), EquipmentSlot::Chest);
+        assert_eq!(Leggings::new().slot(), EquipmentSlot::Legs);
+        assert_eq!(Sword::new().slot(), EquipmentSlot::MainHand);
+        assert_eq!(Wand::new().slot(), EquipmentSlot::MainHand);
+        assert_eq!(Ring::new().slot(), EquipmentSlot::Finger);
+    }
+
+    #[test]
+    fn test_create_effect_saturates_by_default() {
+        let sword = Sword::new();
+        let mut mock_stat = MockStat::new(Stat {
+            health: 0,
+            attack: i32::MAX,
+            defense: 0,
+            magic: 0,
+        });
+        sword.create_effect(&mut mock_stat);
         assert_eq!(
             mock_stat.get_stat(),
             Stat {
                 health: 0,
-                attack: 0,
-                defense: 0,
-                magic: 70
+                attack: i32::MAX,
+                defense: 4,
+                magic: 0
             }
         );
     }
 
+    #[test]
+    fn test_create_effect_with_checked_policy() {
+        let sword = Sword::new();
+        let stat = Stat {
+            health: 0,
+            attack: i32::MAX,
+            defense: 0,
+            magic: 0,
+        };
+        let mut mock_stat = MockStat::new(stat);
+        assert_eq!(
+            sword.create_effect_with(&mut mock_stat, OverflowPolicy::Checked),
+            Err(StatError::Overflow)
+        );
+        assert_eq!(mock_stat.get_stat(), stat);
+    }
 }
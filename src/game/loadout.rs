@@ -0,0 +1,356 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::game::equipment_set::{EquipmentSet, SetBonus};
+use crate::game::item::EquipmentTrait;
+use crate::game::modifier::{apply_modifiers, StatModifier};
+use crate::game::stat::Stat;
+
+/// Where a piece of equipment is worn, a `Loadout` holds one item per slot.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum EquipmentSlot {
+    Head,
+    Chest,
+    Legs,
+    MainHand,
+    OffHand,
+    Finger,
+}
+
+impl fmt::Display for EquipmentSlot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EquipmentSlot::Head => "head",
+            EquipmentSlot::Chest => "chest",
+            EquipmentSlot::Legs => "legs",
+            EquipmentSlot::MainHand => "main hand",
+            EquipmentSlot::OffHand => "off hand",
+            EquipmentSlot::Finger => "finger",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LoadoutError {
+    /// The slot already holds an item, unequip it first.
+    SlotOccupied(EquipmentSlot),
+}
+
+impl fmt::Display for LoadoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadoutError::SlotOccupied(slot) => write!(f, "the {} slot is already occupied", slot),
+        }
+    }
+}
+
+impl Error for LoadoutError {}
+
+/// The equipment currently worn, at most one item per `EquipmentSlot`.
+///
+/// Worn items of the same `EquipmentSet` grant its bonuses on top of their
+/// own stats, a new loadout knows the `EquipmentSet::standard` sets.
+pub struct Loadout {
+    items: BTreeMap<EquipmentSlot, Box<dyn EquipmentTrait>>,
+    sets: Vec<EquipmentSet>,
+}
+
+impl Loadout {
+    pub fn new() -> Self {
+        Self {
+            items: BTreeMap::new(),
+            sets: EquipmentSet::standard(),
+        }
+    }
+
+    /// Replace the known sets.
+    pub fn with_sets(mut self, sets: Vec<EquipmentSet>) -> Self {
+        self.sets = sets;
+        self
+    }
+
+    pub fn sets(&self) -> &[EquipmentSet] {
+        &self.sets
+    }
+
+    /// Wear an item in its slot.
+    /// # Examples
+    /// ```
+    /// use game::{EquipmentSlot, Helmet, Loadout, LoadoutError};
+    /// let mut loadout = Loadout::new();
+    /// assert_eq!(loadout.equip(Helmet::new()), Ok(()));
+    /// assert_eq!(loadout.equip(Helmet::new()), Err(LoadoutError::SlotOccupied(EquipmentSlot::Head)));
+    /// ```
+    pub fn equip<I: EquipmentTrait + 'static>(&mut self, item: I) -> Result<(), LoadoutError> {
+        self.equip_boxed(Box::new(item))
+    }
+
+    pub fn equip_boxed(&mut self, item: Box<dyn EquipmentTrait>) -> Result<(), LoadoutError> {
+        let slot = item.slot();
+        if self.items.contains_key(&slot) {
+            return Err(LoadoutError::SlotOccupied(slot));
+        }
+        self.items.insert(slot, item);
+        Ok(())
+    }
+
+    /// Wear an item, handing back whatever was in its slot before.
+    pub fn swap<I>(&mut self, item: I) -> Option<Box<dyn EquipmentTrait>>
+    where
+        I: EquipmentTrait + 'static,
+    {
+        self.items.insert(item.slot(), Box::new(item))
+    }
+
+    pub fn unequip(&mut self, slot: EquipmentSlot) -> Option<Box<dyn EquipmentTrait>> {
+        self.items.remove(&slot)
+    }
+
+    pub fn get(&self, slot: EquipmentSlot) -> Option<&dyn EquipmentTrait> {
+        self.items.get(&slot).map(|item| item.as_ref())
+    }
+
+    pub fn is_free(&self, slot: EquipmentSlot) -> bool {
+        !self.items.contains_key(&slot)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Worn items ordered by slot.
+    pub fn iter(&self) -> impl Iterator<Item = (EquipmentSlot, &dyn EquipmentTrait)> {
+        self.items.iter().map(|(slot, item)| (*slot, item.as_ref()))
+    }
+
+    /// Flat `Stat` granted by all worn items.
+    pub fn bonus(&self) -> Stat {
+        self.items
+            .values()
+            .fold(Stat::default(), |acc, item| acc.saturating_add(item.stat()))
+    }
+
+    /// Modifiers of all worn items.
+    pub fn modifiers(&self) -> impl Iterator<Item = &StatModifier> {
+        self.items.values().flat_map(|item| item.modifiers())
+    }
+
+    /// Number of worn pieces of the set called `name`.
+    pub fn set_pieces(&self, name: &str) -> usize {
+        self.items
+            .values()
+            .filter(|item| item.set() == Some(name))
+            .count()
+    }
+
+    /// Set bonuses granted by the worn items.
+    /// # Examples
+    /// ```
+    /// use game::{ChestPlate, Helmet, Leggings, Loadout};
+    /// let mut loadout = Loadout::new();
+    /// loadout.equip(Helmet::new()).unwrap();
+    /// assert_eq!(loadout.set_bonuses().count(), 0);
+    /// loadout.equip(ChestPlate::new()).unwrap();
+    /// loadout.equip(Leggings::new()).unwrap();
+    /// assert_eq!(loadout.set_bonuses().count(), 2);
+    /// ```
+    pub fn set_bonuses(&self) -> impl Iterator<Item = &SetBonus> {
+        self.sets
+            .iter()
+            .flat_map(|set| set.active(self.set_pieces(set.name)))
+    }
+
+    /// The `base` stat plus the flat bonus of every worn item, with all item
+    /// modifiers applied on top (see `apply_modifiers` for the order).
+    /// Set bonuses are left out, see `apply`.
+    pub fn apply_items(&self, base: Stat) -> Stat {
+        apply_modifiers(base.saturating_add(self.bonus()), self.modifiers())
+    }
+
+    /// Like `apply_items`, with the flat stat and the modifiers of the active
+    /// set bonuses added to those of the items.
+    pub fn apply(&self, base: Stat) -> Stat {
+        let sets = self
+            .set_bonuses()
+            .fold(Stat::default(), |acc, bonus| acc.saturating_add(bonus.stat));
+        let modifiers = self
+            .modifiers()
+            .chain(self.set_bonuses().flat_map(|bonus| &bonus.modifiers));
+        apply_modifiers(
+            base.saturating_add(self.bonus()).saturating_add(sets),
+            modifiers,
+        )
+    }
+}
+
+impl Default for Loadout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod loadout_tests {
+    use super::*;
+    use crate::game::affix::{AffixPool, Rarity};
+    use crate::game::item::{ChestPlate, Helmet, ItemTrait, Leggings, Ring, Sword, Wand};
+    use crate::game::rng::SeededRng;
+
+    #[test]
+    fn test_new_loadout_is_empty() {
+        let loadout = Loadout::new();
+        assert!(loadout.is_empty());
+        assert_eq!(loadout.bonus(), Stat::default());
+    }
+
+    #[test]
+    fn test_equip_one_item_per_slot() {
+        let mut loadout = Loadout::new();
+        assert_eq!(loadout.equip(Helmet::new()), Ok(()));
+        assert_eq!(loadout.equip(ChestPlate::new()), Ok(()));
+        assert_eq!(loadout.equip(Leggings::new()), Ok(()));
+        assert_eq!(loadout.equip(Sword::new()), Ok(()));
+        assert_eq!(loadout.equip(Ring::new()), Ok(()));
+        assert_eq!(loadout.len(), 5);
+    }
+
+    #[test]
+    fn test_equip_rejects_occupied_slot() {
+        let mut loadout = Loadout::new();
+        loadout.equip(Sword::new()).unwrap();
+        assert_eq!(
+            loadout.equip(Wand::new()),
+            Err(LoadoutError::SlotOccupied(EquipmentSlot::MainHand))
+        );
+        assert_eq!(
+            loadout.get(EquipmentSlot::MainHand).unwrap().stat(),
+            Sword::new().stat()
+        );
+    }
+
+    #[test]
+    fn test_swap_returns_previous_item() {
+        let mut loadout = Loadout::new();
+        assert!(loadout.swap(Sword::new()).is_none());
+        let previous = loadout.swap(Wand::new()).unwrap();
+        assert_eq!(previous.stat(), Sword::new().stat());
+        assert_eq!(
+            loadout.get(EquipmentSlot::MainHand).unwrap().stat(),
+            Wand::new().stat()
+        );
+    }
+
+    #[test]
+    fn test_unequip_frees_slot() {
+        let mut loadout = Loadout::new();
+        loadout.equip(Helmet::new()).unwrap();
+        assert!(!loadout.is_free(EquipmentSlot::Head));
+        assert!(loadout.unequip(EquipmentSlot::Head).is_some());
+        assert!(loadout.is_free(EquipmentSlot::Head));
+        assert!(loadout.unequip(EquipmentSlot::Head).is_none());
+    }
+
+    #[test]
+    fn test_loadout_bonus() {
+        let mut loadout = Loadout::new();
+        loadout.equip(Helmet::new()).unwrap();
+        loadout.equip(ChestPlate::new()).unwrap();
+        loadout.equip(Leggings::new()).unwrap();
+        loadout.equip(Sword::new()).unwrap();
+        assert_eq!(
+            loadout.bonus(),
+            Stat {
+                health: 0,
+                attack: 15,
+                defense: 39,
+                magic: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_plate_set_bonus() {
+        let base = Stat {
+            health: 100,
+            attack: 10,
+            defense: 10,
+            magic: 0,
+        };
+        let mut loadout = Loadout::new();
+        loadout.equip(Helmet::new()).unwrap();
+        assert_eq!(loadout.apply(base), loadout.apply_items(base));
+        loadout.equip(ChestPlate::new()).unwrap();
+        assert_eq!(loadout.set_pieces("Plate"), 2);
+        assert_eq!(loadout.apply(base).defense, 10 + 25 + 5);
+        loadout.equip(Leggings::new()).unwrap();
+        assert_eq!(loadout.apply_items(base).defense, 45);
+        let stat = loadout.apply(base);
+        // (10 + 35 + 5) * 1.1 = 55
+        assert_eq!(stat.defense, 55);
+        assert_eq!(stat.health, 120);
+    }
+
+    #[test]
+    fn test_rolled_pieces_count_toward_set() {
+        let mut loadout = Loadout::new();
+        let mut rng = SeededRng::new(0);
+        let pool = AffixPool::standard();
+        loadout
+            .equip(pool.roll(Helmet::new(), Rarity::Rare, &mut rng))
+            .unwrap();
+        loadout.equip(Leggings::new()).unwrap();
+        assert_eq!(loadout.set_pieces("Plate"), 2);
+        assert_eq!(loadout.set_bonuses().count(), 1);
+    }
+
+    #[test]
+    fn test_without_sets() {
+        let mut loadout = Loadout::new().with_sets(Vec::new());
+        loadout.equip(Helmet::new()).unwrap();
+        loadout.equip(ChestPlate::new()).unwrap();
+        loadout.equip(Leggings::new()).unwrap();
+        assert_eq!(loadout.set_bonuses().count(), 0);
+        assert_eq!(loadout.apply(Stat::default()), loadout.bonus());
+    }
+
+    #[test]
+    fn test_custom_set() {
+        let duelist = EquipmentSet::new("Plate").with_bonus(
+            1,
+            Stat {
+                health: 0,
+                attack: 3,
+                defense: 0,
+                magic: 0,
+            },
+            Vec::new(),
+        );
+        let mut loadout = Loadout::new().with_sets(vec![duelist]);
+        loadout.equip(Helmet::new()).unwrap();
+        assert_eq!(loadout.apply(Stat::default()).attack, 3);
+    }
+
+    #[test]
+    fn test_loadout_iter_ordered_by_slot() {
+        let mut loadout = Loadout::new();
+        loadout.equip(Sword::new()).unwrap();
+        loadout.equip(Helmet::new()).unwrap();
+        let slots: Vec<EquipmentSlot> = loadout.iter().map(|(slot, _)| slot).collect();
+        assert_eq!(slots, vec![EquipmentSlot::Head, EquipmentSlot::MainHand]);
+    }
+
+    #[test]
+    fn test_loadout_error_display() {
+        assert_eq!(
+            LoadoutError::SlotOccupied(EquipmentSlot::MainHand).to_string(),
+            "the main hand slot is already occupied"
+        );
+    }
+}
@@ -0,0 +1,419 @@
+use crate::game::affix::{AffixPool, Rarity};
+use crate::game::item::{
+    BloodBag, ChestPlate, ConsumableTrait, EquipmentTrait, Helmet, ItemCategory, Leggings, Ring,
+    StrengthPotion, Sword, Wand,
+};
+use crate::game::rng::Rng;
+
+/// The item types a `LootTable` can drop.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ItemKind {
+    Helmet,
+    ChestPlate,
+    Leggings,
+    Sword,
+    Wand,
+    Ring,
+    BloodBag,
+    StrengthPotion,
+}
+
+impl ItemKind {
+    pub const ALL: [ItemKind; 8] = [
+        ItemKind::Helmet,
+        ItemKind::ChestPlate,
+        ItemKind::Leggings,
+        ItemKind::Sword,
+        ItemKind::Wand,
+        ItemKind::Ring,
+        ItemKind::BloodBag,
+        ItemKind::StrengthPotion,
+    ];
+
+    /// The `ItemTrait::name` of the items created.
+    pub fn name(self) -> &'static str {
+        match self {
+            ItemKind::Helmet => "Helmet",
+            ItemKind::ChestPlate => "Chest Plate",
+            ItemKind::Leggings => "Leggings",
+            ItemKind::Sword => "Sword",
+            ItemKind::Wand => "Wand",
+            ItemKind::Ring => "Ring",
+            ItemKind::BloodBag => "Blood Bag",
+            ItemKind::StrengthPotion => "Strength Potion",
+        }
+    }
+
+    pub fn category(self) -> ItemCategory {
+        match self {
+            ItemKind::BloodBag | ItemKind::StrengthPotion => ItemCategory::Consumable,
+            _ => ItemCategory::Equipment,
+        }
+    }
+}
+
+/// Something a `LootTable` can yield.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LootEntry {
+    /// A new item, equipment gets affixes of the given rarity or a rolled one.
+    Item {
+        kind: ItemKind,
+        rarity: Option<Rarity>,
+    },
+    /// An amount of gold in `min..=max`.
+    Gold { min: u32, max: u32 },
+    /// Everything rolled by a nested table.
+    Table(LootTable),
+    /// A weighted entry that drops nothing.
+    Nothing,
+}
+
+impl LootEntry {
+    /// An item with a rolled rarity.
+    pub fn item(kind: ItemKind) -> Self {
+        LootEntry::Item { kind, rarity: None }
+    }
+
+    pub fn item_of_rarity(kind: ItemKind, rarity: Rarity) -> Self {
+        LootEntry::Item {
+            kind,
+            rarity: Some(rarity),
+        }
+    }
+
+    pub fn gold(min: u32, max: u32) -> Self {
+        LootEntry::Gold { min, max }
+    }
+
+    fn drop_into<R: Rng + ?Sized>(&self, loot: &mut Loot, pool: &AffixPool, rng: &mut R) {
+        match self {
+            LootEntry::Item { kind, rarity } => {
+                let rarity = *rarity;
+                match kind {
+                    ItemKind::Helmet => {
+                        loot.equipment
+                            .push(rolled(Helmet::new(), rarity, pool, rng))
+                    }
+                    ItemKind::ChestPlate => {
+                        loot.equipment
+                            .push(rolled(ChestPlate::new(), rarity, pool, rng))
+                    }
+                    ItemKind::Leggings => {
+                        loot.equipment
+                            .push(rolled(Leggings::new(), rarity, pool, rng))
+                    }
+                    ItemKind::Sword => loot.equipment.push(rolled(Sword::new(), rarity, pool, rng)),
+                    ItemKind::Wand => loot.equipment.push(rolled(Wand::new(), rarity, pool, rng)),
+                    ItemKind::Ring => loot.equipment.push(rolled(Ring::new(), rarity, pool, rng)),
+                    ItemKind::BloodBag => loot.consumables.push(Box::new(BloodBag::new())),
+                    ItemKind::StrengthPotion => {
+                        loot.consumables.push(Box::new(StrengthPotion::new()))
+                    }
+                }
+            }
+            LootEntry::Gold { min, max } => {
+                let span = max.saturating_sub(*min) as u64 + 1;
+                loot.gold = loot.gold.saturating_add(min + rng.below(span) as u32);
+            }
+            LootEntry::Table(table) => table.roll_into(loot, pool, rng),
+            LootEntry::Nothing => {}
+        }
+    }
+}
+
+/// What a `LootTable` dropped, see `Inventory::add_boxed_equipment` and
+/// `Inventory::add_boxed_consumable` to carry it.
+#[derive(Default)]
+pub struct Loot {
+    pub gold: u32,
+    pub equipment: Vec<Box<dyn EquipmentTrait>>,
+    pub consumables: Vec<Box<dyn ConsumableTrait>>,
+}
+
+impl Loot {
+    pub fn is_empty(&self) -> bool {
+        self.gold == 0 && self.equipment.is_empty() && self.consumables.is_empty()
+    }
+}
+
+/// What a defeated enemy yields, rolled with an injected `Rng`.
+///
+/// Every roll drops the guaranteed entries, then each chance entry on its
+/// own, then picks `rolls` times among the weighted entries. Entries can be
+/// nested tables.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LootTable {
+    guaranteed: Vec<LootEntry>,
+    chances: Vec<(f32, LootEntry)>,
+    weighted: Vec<(u32, LootEntry)>,
+    rolls: u32,
+}
+
+impl LootTable {
+    pub fn new() -> Self {
+        Self {
+            guaranteed: Vec::new(),
+            chances: Vec::new(),
+            weighted: Vec::new(),
+            rolls: 1,
+        }
+    }
+
+    pub fn with_guaranteed(mut self, entry: LootEntry) -> Self {
+        self.guaranteed.push(entry);
+        self
+    }
+
+    /// Drop the entry with the given probability, between 0.0 and 1.0.
+    pub fn with_chance(mut self, probability: f32, entry: LootEntry) -> Self {
+        self.chances.push((probability, entry));
+        self
+    }
+
+    pub fn with_weighted(mut self, weight: u32, entry: LootEntry) -> Self {
+        self.weighted.push((weight, entry));
+        self
+    }
+
+    /// How many weighted entries are picked, 1 by default.
+    pub fn with_rolls(mut self, rolls: u32) -> Self {
+        self.rolls = rolls;
+        self
+    }
+
+    /// Roll the table with the standard `AffixPool`.
+    /// # Examples
+    /// ```
+    /// use game::{ItemKind, LootEntry, LootTable, SeededRng};
+    /// let table = LootTable::new()
+    ///     .with_guaranteed(LootEntry::gold(5, 10))
+    ///     .with_weighted(3, LootEntry::item(ItemKind::BloodBag))
+    ///     .with_weighted(1, LootEntry::item(ItemKind::Sword));
+    /// let loot = table.roll(&mut SeededRng::new(42));
+    /// assert!((5..=10).contains(&loot.gold));
+    /// assert_eq!(loot.equipment.len() + loot.consumables.len(), 1);
+    /// ```
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> Loot {
+        self.roll_with(&AffixPool::standard(), rng)
+    }
+
+    /// Roll the table, drawing equipment affixes from the given pool.
+    pub fn roll_with<R: Rng + ?Sized>(&self, pool: &AffixPool, rng: &mut R) -> Loot {
+        let mut loot = Loot::default();
+        self.roll_into(&mut loot, pool, rng);
+        loot
+    }
+
+    fn roll_into<R: Rng + ?Sized>(&self, loot: &mut Loot, pool: &AffixPool, rng: &mut R) {
+        for entry in &self.guaranteed {
+            entry.drop_into(loot, pool, rng);
+        }
+        for (probability, entry) in &self.chances {
+            if rng.chance(*probability) {
+                entry.drop_into(loot, pool, rng);
+            }
+        }
+        let total: u64 = self.weighted.iter().map(|(weight, _)| *weight as u64).sum();
+        if total == 0 {
+            return;
+        }
+        for _ in 0..self.rolls {
+            let mut roll = rng.below(total);
+            for (weight, entry) in &self.weighted {
+                if roll < *weight as u64 {
+                    entry.drop_into(loot, pool, rng);
+                    break;
+                }
+                roll -= *weight as u64;
+            }
+        }
+    }
+}
+
+impl Default for LootTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Add affixes to a piece of equipment, rolling its rarity unless given.
+fn rolled<I: EquipmentTrait + 'static, R: Rng + ?Sized>(
+    item: I,
+    rarity: Option<Rarity>,
+    pool: &AffixPool,
+    rng: &mut R,
+) -> Box<dyn EquipmentTrait> {
+    let rarity = rarity.unwrap_or_else(|| Rarity::roll(rng));
+    Box::new(pool.roll(item, rarity, rng))
+}
+
+#[cfg(test)]
+mod loot_tests {
+    use super::*;
+    use crate::game::affix::{Affix, AffixPool};
+    use crate::game::inventory::Inventory;
+    use crate::game::rng::SeededRng;
+    use crate::game::stat::StatField;
+
+    /// Replays the given values in a loop.
+    struct FixedRng {
+        values: Vec<u64>,
+        next: usize,
+    }
+
+    impl FixedRng {
+        fn new(values: Vec<u64>) -> Self {
+            Self { values, next: 0 }
+        }
+    }
+
+    impl Rng for FixedRng {
+        fn next_u64(&mut self) -> u64 {
+            let value = self.values[self.next % self.values.len()];
+            self.next += 1;
+            value
+        }
+    }
+
+    #[test]
+    fn test_guaranteed_drops() {
+        let table = LootTable::new()
+            .with_guaranteed(LootEntry::item(ItemKind::BloodBag))
+            .with_guaranteed(LootEntry::item_of_rarity(ItemKind::Helmet, Rarity::Common));
+        let loot = table.roll(&mut SeededRng::new(0));
+        assert_eq!(loot.consumables.len(), 1);
+        assert_eq!(loot.consumables[0].name(), "Blood Bag");
+        assert_eq!(loot.equipment.len(), 1);
+        assert_eq!(loot.equipment[0].name(), "Helmet");
+        assert_eq!(loot.gold, 0);
+    }
+
+    #[test]
+    fn test_chance_drops() {
+        let table = LootTable::new()
+            .with_chance(0.0, LootEntry::item(ItemKind::Sword))
+            .with_chance(1.0, LootEntry::item(ItemKind::StrengthPotion));
+        let mut rng = SeededRng::new(1);
+        for _ in 0..20 {
+            let loot = table.roll(&mut rng);
+            assert!(loot.equipment.is_empty());
+            assert_eq!(loot.consumables.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_gold_range() {
+        let table = LootTable::new().with_guaranteed(LootEntry::gold(10, 20));
+        let mut rng = SeededRng::new(2);
+        for _ in 0..100 {
+            assert!((10..=20).contains(&table.roll(&mut rng).gold));
+        }
+        let fixed = LootTable::new().with_guaranteed(LootEntry::gold(7, 7));
+        assert_eq!(fixed.roll(&mut rng).gold, 7);
+    }
+
+    #[test]
+    fn test_weighted_pick_follows_the_rng() {
+        let table = LootTable::new()
+            .with_weighted(1, LootEntry::Nothing)
+            .with_weighted(3, LootEntry::gold(5, 5));
+        assert!(table.roll(&mut FixedRng::new(vec![0])).is_empty());
+        assert_eq!(table.roll(&mut FixedRng::new(vec![1])).gold, 5);
+        assert_eq!(table.roll(&mut FixedRng::new(vec![3])).gold, 5);
+    }
+
+    #[test]
+    fn test_multiple_rolls() {
+        let table = LootTable::new()
+            .with_weighted(1, LootEntry::item(ItemKind::BloodBag))
+            .with_rolls(3);
+        assert_eq!(table.roll(&mut SeededRng::new(3)).consumables.len(), 3);
+    }
+
+    #[test]
+    fn test_empty_weights_drop_nothing() {
+        let table = LootTable::new().with_weighted(0, LootEntry::gold(1, 1));
+        assert!(table.roll(&mut SeededRng::new(4)).is_empty());
+    }
+
+    #[test]
+    fn test_nested_tables() {
+        let gems = LootTable::new().with_guaranteed(LootEntry::gold(50, 50));
+        let table = LootTable::new()
+            .with_guaranteed(LootEntry::gold(1, 1))
+            .with_guaranteed(LootEntry::Table(gems));
+        assert_eq!(table.roll(&mut SeededRng::new(5)).gold, 51);
+    }
+
+    #[test]
+    fn test_rarity_adds_affixes() {
+        let mut pool = AffixPool::new();
+        pool.add(Affix::prefix("Sharp", StatField::Attack, 3, 3));
+        pool.add(Affix::suffix("of the Wolf", StatField::Attack, 2, 2));
+        let table = LootTable::new()
+            .with_guaranteed(LootEntry::item_of_rarity(ItemKind::Sword, Rarity::Rare));
+        let loot = table.roll_with(&pool, &mut SeededRng::new(6));
+        assert_eq!(loot.equipment[0].name(), "Sharp Sword of the Wolf");
+        assert_eq!(loot.equipment[0].stat().attack, 15 + 4 + 3);
+    }
+
+    #[test]
+    fn test_same_seed_same_drops() {
+        let table = LootTable::new()
+            .with_guaranteed(LootEntry::gold(1, 100))
+            .with_chance(0.5, LootEntry::item(ItemKind::Ring))
+            .with_weighted(1, LootEntry::item(ItemKind::Helmet))
+            .with_weighted(1, LootEntry::item(ItemKind::Wand))
+            .with_rolls(2);
+        for seed in 0..20 {
+            let first = table.roll(&mut SeededRng::new(seed));
+            let second = table.roll(&mut SeededRng::new(seed));
+            assert_eq!(first.gold, second.gold);
+            let names = |loot: &Loot| {
+                loot.equipment
+                    .iter()
+                    .map(|item| item.name().to_string())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(names(&first), names(&second));
+        }
+    }
+
+    #[test]
+    fn test_item_kind_name_matches_item() {
+        let mut rng = SeededRng::new(7);
+        for kind in ItemKind::ALL {
+            let entry = LootEntry::item_of_rarity(kind, Rarity::Common);
+            let loot = LootTable::new().with_guaranteed(entry).roll(&mut rng);
+            let name = match kind.category() {
+                ItemCategory::Equipment => loot.equipment[0].name().to_string(),
+                ItemCategory::Consumable => loot.consumables[0].name().to_string(),
+            };
+            assert_eq!(name, kind.name());
+        }
+    }
+
+    #[test]
+    fn test_loot_goes_into_an_inventory() {
+        let table = LootTable::new()
+            .with_guaranteed(LootEntry::item(ItemKind::Sword))
+            .with_guaranteed(LootEntry::item(ItemKind::BloodBag))
+            .with_guaranteed(LootEntry::item(ItemKind::BloodBag));
+        let loot = table.roll(&mut SeededRng::new(8));
+        let mut inventory = Inventory::new();
+        for item in loot.equipment {
+            inventory.add_boxed_equipment(item).unwrap();
+        }
+        for item in loot.consumables {
+            inventory.add_boxed_consumable(item).unwrap();
+        }
+        assert_eq!(inventory.len(), 2);
+        assert_eq!(inventory.count("Blood Bag"), 2);
+    }
+
+    #[test]
+    fn test_item_kind_category() {
+        assert_eq!(ItemKind::BloodBag.category(), ItemCategory::Consumable);
+        assert_eq!(ItemKind::Leggings.category(), ItemCategory::Equipment);
+    }
+}
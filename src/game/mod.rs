@@ -0,0 +1,20 @@
+pub mod affix;
+pub mod battle;
+pub mod character;
+pub mod counter;
+pub mod crafting;
+pub mod equipment;
+pub mod equipment_set;
+pub mod inventory;
+pub mod item;
+pub mod loadout;
+pub mod loot;
+pub mod modifier;
+pub mod profession;
+pub mod progression;
+pub mod promotion;
+pub mod rng;
+pub mod skill;
+pub mod stat;
+pub mod status;
+pub mod vitals;
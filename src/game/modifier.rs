@@ -0,0 +1,191 @@
+use crate::game::stat::{Stat, StatField};
+
+/// How a `StatModifier` changes its field.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ModifierKind {
+    /// Add a fixed amount, e.g. `Flat(10)` is +10.
+    Flat(i32),
+    /// Percentage summed with the other additive percentages, e.g. `AddPercent(10.0)` is +10%.
+    AddPercent(f32),
+    /// Percentage applied on its own on top of everything else, e.g. `MulPercent(-20.0)` is x0.8.
+    MulPercent(f32),
+}
+
+/// A change to a single `Stat` member.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StatModifier {
+    pub field: StatField,
+    pub kind: ModifierKind,
+}
+
+impl StatModifier {
+    pub fn flat(field: StatField, value: i32) -> Self {
+        Self {
+            field,
+            kind: ModifierKind::Flat(value),
+        }
+    }
+
+    pub fn add_percent(field: StatField, percent: f32) -> Self {
+        Self {
+            field,
+            kind: ModifierKind::AddPercent(percent),
+        }
+    }
+
+    pub fn mul_percent(field: StatField, percent: f32) -> Self {
+        Self {
+            field,
+            kind: ModifierKind::MulPercent(percent),
+        }
+    }
+}
+
+/// Apply modifiers over a base `Stat`.
+///
+/// Every field is evaluated independently in this order, so the result does
+/// not depend on the order of the modifiers:
+/// 1. all `Flat` values are added to the base,
+/// 2. the sum is scaled once by `1 + (sum of AddPercent) / 100`,
+/// 3. the result is scaled by `1 + MulPercent / 100` for every `MulPercent`,
+/// 4. the value is rounded to the nearest integer (halfway away from zero)
+///    and saturates at the `i32` bounds.
+/// # Examples
+/// ```
+/// use game::{apply_modifiers, Stat, StatField, StatModifier};
+/// let base = Stat {health: 100, attack: 10, defense: 50, magic: 0};
+/// let modifiers = [
+///     StatModifier::flat(StatField::Defense, 10),
+///     StatModifier::add_percent(StatField::Defense, 10.0),
+///     StatModifier::add_percent(StatField::Defense, 10.0),
+///     StatModifier::mul_percent(StatField::Defense, 50.0),
+/// ];
+/// // ((50 + 10) * 1.2) * 1.5 = 108
+/// assert_eq!(apply_modifiers(base, &modifiers).defense, 108);
+/// ```
+pub fn apply_modifiers<'a, I>(base: Stat, modifiers: I) -> Stat
+where
+    I: IntoIterator<Item = &'a StatModifier>,
+{
+    let mut flat = [0i64; 4];
+    let mut add_percent = [0f64; 4];
+    let mut mul_scale = [1f64; 4];
+    for modifier in modifiers {
+        let index = field_index(modifier.field);
+        match modifier.kind {
+            ModifierKind::Flat(value) => flat[index] += value as i64,
+            ModifierKind::AddPercent(percent) => add_percent[index] += percent as f64,
+            ModifierKind::MulPercent(percent) => mul_scale[index] *= 1.0 + percent as f64 / 100.0,
+        }
+    }
+
+    let mut stat = base;
+    for field in StatField::ALL {
+        let index = field_index(field);
+        let value = (base.get(field) as i64 + flat[index]) as f64
+            * (1.0 + add_percent[index] / 100.0)
+            * mul_scale[index];
+        stat.set(field, value.round() as i32);
+    }
+    stat
+}
+
+fn field_index(field: StatField) -> usize {
+    match field {
+        StatField::Health => 0,
+        StatField::Attack => 1,
+        StatField::Defense => 2,
+        StatField::Magic => 3,
+    }
+}
+
+#[cfg(test)]
+mod modifier_tests {
+    use super::*;
+
+    fn base() -> Stat {
+        Stat {
+            health: 100,
+            attack: 40,
+            defense: 50,
+            magic: 0,
+        }
+    }
+
+    #[test]
+    fn test_no_modifiers() {
+        assert_eq!(apply_modifiers(base(), &[]), base());
+    }
+
+    #[test]
+    fn test_flat_modifiers() {
+        let modifiers = [
+            StatModifier::flat(StatField::Attack, 15),
+            StatModifier::flat(StatField::Attack, -5),
+            StatModifier::flat(StatField::Magic, 7),
+        ];
+        assert_eq!(
+            apply_modifiers(base(), &modifiers),
+            Stat {
+                health: 100,
+                attack: 50,
+                defense: 50,
+                magic: 7
+            }
+        );
+    }
+
+    #[test]
+    fn test_add_percent_modifiers_are_summed() {
+        let modifiers = [
+            StatModifier::add_percent(StatField::Defense, 10.0),
+            StatModifier::add_percent(StatField::Defense, 10.0),
+        ];
+        assert_eq!(apply_modifiers(base(), &modifiers).defense, 60);
+    }
+
+    #[test]
+    fn test_mul_percent_modifiers_are_compounded() {
+        let modifiers = [
+            StatModifier::mul_percent(StatField::Defense, 10.0),
+            StatModifier::mul_percent(StatField::Defense, 10.0),
+        ];
+        // 50 * 1.1 * 1.1 = 60.5
+        assert_eq!(apply_modifiers(base(), &modifiers).defense, 61);
+    }
+
+    #[test]
+    fn test_flat_applies_before_percent() {
+        let modifiers = [
+            StatModifier::add_percent(StatField::Attack, 50.0),
+            StatModifier::flat(StatField::Attack, 10),
+        ];
+        assert_eq!(apply_modifiers(base(), &modifiers).attack, 75);
+    }
+
+    #[test]
+    fn test_modifier_order_does_not_matter() {
+        let mut modifiers = vec![
+            StatModifier::mul_percent(StatField::Health, -20.0),
+            StatModifier::flat(StatField::Health, 25),
+            StatModifier::add_percent(StatField::Health, 30.0),
+        ];
+        let expect = apply_modifiers(base(), &modifiers);
+        modifiers.reverse();
+        assert_eq!(apply_modifiers(base(), &modifiers), expect);
+        // (100 + 25) * 1.3 * 0.8 = 130
+        assert_eq!(expect.health, 130);
+    }
+
+    #[test]
+    fn test_modifiers_saturate() {
+        let modifiers = [StatModifier::mul_percent(StatField::Health, 1000.0)];
+        let base = Stat {
+            health: i32::MAX,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        };
+        assert_eq!(apply_modifiers(base, &modifiers).health, i32::MAX);
+    }
+}
@@ -1,12 +1,101 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use crate::game::counter::CounterMatrix;
+use crate::game::progression::GrowthCurve;
+use crate::game::skill::{Skill, SkillCost, SkillEffect, SkillTarget};
 use crate::game::stat::{Stat, StatTrait};
+use crate::game::status::StatusEffect;
 
-pub trait Profession {
+/// A character class.
+///
+/// The trait is object safe, so professions can be mixed behind
+/// `Box<dyn Profession>`. Arguments are trait objects, any `&T` with
+/// `T: Profession` or `T: StatTrait` coerces to them, e.g.
+/// `warrior.effective_against(&knight, counters)`.
+/// # Examples
+/// ```
+/// use game::{CounterMatrix, Knight, Profession, ProfessionType, Sorcerer, Warrior};
+/// let counters = CounterMatrix::standard();
+/// let party: Vec<Box<dyn Profession>> = vec![
+///     Box::new(Warrior::new()),
+///     Box::new(Knight::new()),
+///     Box::new(Sorcerer::new()),
+/// ];
+/// assert!(party[0].effective_against(party[1].as_ref(), counters));
+/// assert_eq!(party[2].profession_type(), ProfessionType::SorcererType);
+/// ```
+pub trait Profession: Send + Sync {
     fn profession_type(&self) -> ProfessionType;
-    fn effective_against<P: Profession>(&self, profession: &P) -> bool;
-    fn suppressed_by<P: Profession>(&self, profession: &P) -> bool;
 
-    fn attack_points<T: StatTrait>(&self, stat_trait: &T) -> i32;
-    fn defense_points<T: StatTrait>(&self, stat_trait: &T) -> i32;
+    /// Name of the class, differs from the `ProfessionType` name for promoted
+    /// classes, see `PromotionTree`.
+    fn class_name(&self) -> &'static str {
+        self.profession_type().name()
+    }
+
+    /// Level 1 `Stat` of the profession.
+    fn base_stat(&self) -> Stat;
+    /// Stat gained on each level up.
+    fn growth(&self) -> GrowthCurve;
+
+    /// Abilities a `Battle` can use instead of a plain attack, in order of preference.
+    fn skills(&self) -> Vec<Skill> {
+        Vec::new()
+    }
+
+    /// Whether attacking `profession` is boosted by `counters`.
+    fn effective_against(&self, profession: &dyn Profession, counters: &CounterMatrix) -> bool {
+        counters.is_effective(self.profession_type(), profession.profession_type())
+    }
+
+    /// Whether `profession` is effective against this one under `counters`.
+    fn suppressed_by(&self, profession: &dyn Profession, counters: &CounterMatrix) -> bool {
+        counters.is_effective(profession.profession_type(), self.profession_type())
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32;
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32;
+}
+
+impl<P: Profession + ?Sized> Profession for Box<P> {
+    fn profession_type(&self) -> ProfessionType {
+        (**self).profession_type()
+    }
+
+    fn class_name(&self) -> &'static str {
+        (**self).class_name()
+    }
+
+    fn base_stat(&self) -> Stat {
+        (**self).base_stat()
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        (**self).growth()
+    }
+
+    fn skills(&self) -> Vec<Skill> {
+        (**self).skills()
+    }
+
+    fn effective_against(&self, profession: &dyn Profession, counters: &CounterMatrix) -> bool {
+        (**self).effective_against(profession, counters)
+    }
+
+    fn suppressed_by(&self, profession: &dyn Profession, counters: &CounterMatrix) -> bool {
+        (**self).suppressed_by(profession, counters)
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        (**self).attack_points(stat_trait)
+    }
+
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        (**self).defense_points(stat_trait)
+    }
 }
 
 pub struct Warrior {
@@ -24,26 +113,51 @@ impl Warrior {
             },
         }
     }
+
+    pub fn stat(&self) -> Stat {
+        self.stat
+    }
 }
 
+impl Default for Warrior {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Profession for Warrior {
     fn profession_type(&self) -> ProfessionType {
         ProfessionType::WarriorType
     }
-    fn effective_against<P: Profession>(&self, profession: &P) -> bool {
-        matches! (profession.profession_type(), ProfessionType::KnightType)
+
+    fn base_stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        GrowthCurve::Linear(Stat {
+            health: 9,
+            attack: 3,
+            defense: 4,
+            magic: 0,
+        })
     }
 
-    fn suppressed_by<P: Profession>(&self, profession: &P) -> bool {
-        matches! (profession.profession_type(), ProfessionType::SorcererType)
+    fn skills(&self) -> Vec<Skill> {
+        vec![Skill {
+            name: "Cleave",
+            cost: SkillCost::Health(8),
+            cooldown: 3,
+            target: SkillTarget::Opponent,
+            effect: SkillEffect::Damage { power: 1.5 },
+        }]
     }
 
-    fn attack_points<T: StatTrait>(&self, stat_trait: &T) -> i32 {
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
         stat_trait.get_stat().attack
     }
 
-    fn defense_points<T: StatTrait>(&self, stat_trait: &T) -> i32 {
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
         stat_trait.get_stat().defense
     }
 }
@@ -63,6 +177,16 @@ impl Sorcerer {
             },
         }
     }
+
+    pub fn stat(&self) -> Stat {
+        self.stat
+    }
+}
+
+impl Default for Sorcerer {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Profession for Sorcerer {
@@ -70,22 +194,37 @@ impl Profession for Sorcerer {
         ProfessionType::SorcererType
     }
 
-    fn effective_against<P: Profession>(&self, profession: &P) -> bool {
-        matches! (profession.profession_type(), ProfessionType::WarriorType)
+    fn base_stat(&self) -> Stat {
+        self.stat
     }
 
-    fn suppressed_by<P: Profession>(&self, profession: &P) -> bool {
-        matches! (profession.profession_type(), ProfessionType::KnightType)
+    fn growth(&self) -> GrowthCurve {
+        GrowthCurve::Linear(Stat {
+            health: 6,
+            attack: 0,
+            defense: 1,
+            magic: 6,
+        })
     }
-    fn attack_points<T: StatTrait>(&self, stat_trait: &T) -> i32 {
+
+    fn skills(&self) -> Vec<Skill> {
+        vec![Skill {
+            name: "Fireball",
+            cost: SkillCost::Mana(20),
+            cooldown: 2,
+            target: SkillTarget::Opponent,
+            effect: SkillEffect::Damage { power: 1.6 },
+        }]
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
         stat_trait.get_stat().magic
     }
-    fn defense_points<T: StatTrait>(&self, stat_trait: &T) -> i32 {
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
         stat_trait.get_stat().defense
     }
 }
 
-
 pub struct Knight {
     stat: Stat,
 }
@@ -101,35 +240,425 @@ impl Knight {
             },
         }
     }
+
+    pub fn stat(&self) -> Stat {
+        self.stat
+    }
+}
+
+impl Default for Knight {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Profession for Knight {
     fn profession_type(&self) -> ProfessionType {
         ProfessionType::KnightType
     }
-    fn effective_against<P: Profession>(&self, profession: &P) -> bool {
-        matches! (profession.profession_type(), ProfessionType::SorcererType)
+
+    fn base_stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        // Knights harden early and grow slower afterwards.
+        GrowthCurve::Table(vec![
+            Stat {
+                health: 14,
+                attack: 3,
+                defense: 5,
+                magic: 0,
+            },
+            Stat {
+                health: 12,
+                attack: 3,
+                defense: 4,
+                magic: 0,
+            },
+            Stat {
+                health: 10,
+                attack: 2,
+                defense: 3,
+                magic: 0,
+            },
+        ])
+    }
+
+    fn skills(&self) -> Vec<Skill> {
+        vec![Skill {
+            name: "Shield Wall",
+            cost: SkillCost::Free,
+            cooldown: 4,
+            target: SkillTarget::User,
+            effect: SkillEffect::ModifyStat(Stat {
+                health: 0,
+                attack: 0,
+                defense: 10,
+                magic: 0,
+            }),
+        }]
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        stat_trait.get_stat().attack
+    }
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        stat_trait.get_stat().defense
+    }
+}
+
+pub struct Archer {
+    stat: Stat,
+}
+
+impl Archer {
+    pub fn new() -> Self {
+        Self {
+            stat: Stat {
+                health: 75,
+                attack: 45,
+                defense: 20,
+                magic: 0,
+            },
+        }
+    }
+
+    pub fn stat(&self) -> Stat {
+        self.stat
+    }
+}
+
+impl Default for Archer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profession for Archer {
+    fn profession_type(&self) -> ProfessionType {
+        ProfessionType::ArcherType
+    }
+
+    fn base_stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        GrowthCurve::Linear(Stat {
+            health: 7,
+            attack: 4,
+            defense: 1,
+            magic: 0,
+        })
     }
 
-    fn suppressed_by<P: Profession>(&self, profession: &P) -> bool {
-        matches! (profession.profession_type(), ProfessionType::WarriorType)
+    fn skills(&self) -> Vec<Skill> {
+        vec![Skill {
+            name: "Aimed Shot",
+            cost: SkillCost::Health(5),
+            cooldown: 2,
+            target: SkillTarget::Opponent,
+            effect: SkillEffect::Damage { power: 1.4 },
+        }]
     }
-    fn attack_points<T: StatTrait>(&self, stat_trait: &T) -> i32 {
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
         stat_trait.get_stat().attack
     }
-    fn defense_points<T: StatTrait>(&self, stat_trait: &T) -> i32 {
+
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        stat_trait.get_stat().defense
+    }
+}
+
+/// A healer, its magic goes into mending wounds and warding off attacks.
+pub struct Cleric {
+    stat: Stat,
+}
+
+impl Cleric {
+    pub fn new() -> Self {
+        Self {
+            stat: Stat {
+                health: 80,
+                attack: 10,
+                defense: 30,
+                magic: 60,
+            },
+        }
+    }
+
+    pub fn stat(&self) -> Stat {
+        self.stat
+    }
+}
+
+impl Default for Cleric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profession for Cleric {
+    fn profession_type(&self) -> ProfessionType {
+        ProfessionType::ClericType
+    }
+
+    fn base_stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        GrowthCurve::Linear(Stat {
+            health: 8,
+            attack: 1,
+            defense: 2,
+            magic: 5,
+        })
+    }
+
+    fn skills(&self) -> Vec<Skill> {
+        vec![Skill {
+            name: "Heal",
+            cost: SkillCost::Mana(15),
+            cooldown: 3,
+            target: SkillTarget::User,
+            effect: SkillEffect::Heal { power: 0.4 },
+        }]
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        stat_trait.get_stat().magic / 2
+    }
+
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        let stat = stat_trait.get_stat();
+        stat.defense.saturating_add(stat.magic / 2)
+    }
+}
+
+/// Mixes blades with tricks, half of its magic adds to the attack.
+pub struct Rogue {
+    stat: Stat,
+}
+
+impl Rogue {
+    pub fn new() -> Self {
+        Self {
+            stat: Stat {
+                health: 70,
+                attack: 35,
+                defense: 25,
+                magic: 20,
+            },
+        }
+    }
+
+    pub fn stat(&self) -> Stat {
+        self.stat
+    }
+}
+
+impl Default for Rogue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profession for Rogue {
+    fn profession_type(&self) -> ProfessionType {
+        ProfessionType::RogueType
+    }
+
+    fn base_stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        GrowthCurve::Linear(Stat {
+            health: 6,
+            attack: 3,
+            defense: 2,
+            magic: 2,
+        })
+    }
+
+    fn skills(&self) -> Vec<Skill> {
+        vec![
+            Skill {
+                name: "Backstab",
+                cost: SkillCost::Mana(10),
+                cooldown: 3,
+                target: SkillTarget::Opponent,
+                effect: SkillEffect::Damage { power: 1.8 },
+            },
+            Skill {
+                name: "Poison Blade",
+                cost: SkillCost::Mana(5),
+                cooldown: 4,
+                target: SkillTarget::Opponent,
+                effect: SkillEffect::Status(StatusEffect::poison(4, 3)),
+            },
+        ]
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        let stat = stat_trait.get_stat();
+        stat.attack.saturating_add(stat.magic / 2)
+    }
+
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
         stat_trait.get_stat().defense
     }
 }
 
+/// A holy knight, a quarter of its magic adds to both attack and defense.
+pub struct Paladin {
+    stat: Stat,
+}
+
+impl Paladin {
+    pub fn new() -> Self {
+        Self {
+            stat: Stat {
+                health: 100,
+                attack: 35,
+                defense: 45,
+                magic: 20,
+            },
+        }
+    }
+
+    pub fn stat(&self) -> Stat {
+        self.stat
+    }
+}
+
+impl Default for Paladin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profession for Paladin {
+    fn profession_type(&self) -> ProfessionType {
+        ProfessionType::PaladinType
+    }
+
+    fn base_stat(&self) -> Stat {
+        self.stat
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        GrowthCurve::Linear(Stat {
+            health: 9,
+            attack: 2,
+            defense: 3,
+            magic: 2,
+        })
+    }
+
+    fn skills(&self) -> Vec<Skill> {
+        vec![Skill {
+            name: "Smite",
+            cost: SkillCost::Mana(10),
+            cooldown: 3,
+            target: SkillTarget::Opponent,
+            effect: SkillEffect::Damage { power: 1.4 },
+        }]
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        let stat = stat_trait.get_stat();
+        stat.attack.saturating_add(stat.magic / 4)
+    }
+
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        let stat = stat_trait.get_stat();
+        stat.defense.saturating_add(stat.magic / 4)
+    }
+}
 
-#[derive(Debug, PartialEq)]
-enum ProfessionType {
+/// Identifies a profession, e.g. to look up counters or to parse configs.
+///
+/// Displayed and parsed by its lowercase class name.
+/// # Examples
+/// ```
+/// use game::ProfessionType;
+/// let parsed: ProfessionType = "Knight".parse().unwrap();
+/// assert_eq!(parsed, ProfessionType::KnightType);
+/// assert_eq!(parsed.to_string(), "knight");
+/// assert_eq!(ProfessionType::ALL.len(), 7);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProfessionType {
     WarriorType,
     SorcererType,
     KnightType,
+    ArcherType,
+    ClericType,
+    RogueType,
+    PaladinType,
 }
 
+impl ProfessionType {
+    pub const ALL: [ProfessionType; 7] = [
+        ProfessionType::WarriorType,
+        ProfessionType::SorcererType,
+        ProfessionType::KnightType,
+        ProfessionType::ArcherType,
+        ProfessionType::ClericType,
+        ProfessionType::RogueType,
+        ProfessionType::PaladinType,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = ProfessionType> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProfessionType::WarriorType => "warrior",
+            ProfessionType::SorcererType => "sorcerer",
+            ProfessionType::KnightType => "knight",
+            ProfessionType::ArcherType => "archer",
+            ProfessionType::ClericType => "cleric",
+            ProfessionType::RogueType => "rogue",
+            ProfessionType::PaladinType => "paladin",
+        }
+    }
+}
+
+impl fmt::Display for ProfessionType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The name passed to `ProfessionType::from_str` is not a known profession.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseProfessionTypeError(pub String);
+
+impl fmt::Display for ParseProfessionTypeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown profession `{}`", self.0)
+    }
+}
+
+impl Error for ParseProfessionTypeError {}
+
+impl FromStr for ProfessionType {
+    type Err = ParseProfessionTypeError;
+
+    /// Parse a class name, ignoring case and surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        Self::iter()
+            .find(|profession| profession.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| ParseProfessionTypeError(name.to_string()))
+    }
+}
 
 #[cfg(test)]
 mod profession_tests {
@@ -138,19 +667,151 @@ mod profession_tests {
     #[test]
     fn test_new_warrior_stat() {
         let warrior = Warrior::new();
-        assert_eq!(warrior.stat, Stat { health: 90, attack: 40, defense: 55, magic: 0 });
+        assert_eq!(
+            warrior.stat,
+            Stat {
+                health: 90,
+                attack: 40,
+                defense: 55,
+                magic: 0
+            }
+        );
     }
 
     #[test]
     fn test_new_sorcerer_stat() {
         let sorcerer = Sorcerer::new();
-        assert_eq!(sorcerer.stat, Stat { health: 70, attack: 0, defense: 20, magic: 50 });
+        assert_eq!(
+            sorcerer.stat,
+            Stat {
+                health: 70,
+                attack: 0,
+                defense: 20,
+                magic: 50
+            }
+        );
     }
 
     #[test]
     fn test_new_knight_stat() {
         let knight = Knight::new();
-        assert_eq!(knight.stat, Stat { health: 100, attack: 40, defense: 30, magic: 0 });
+        assert_eq!(
+            knight.stat,
+            Stat {
+                health: 100,
+                attack: 40,
+                defense: 30,
+                magic: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_profession_base_stat() {
+        assert_eq!(Warrior::new().base_stat(), Warrior::new().stat);
+        assert_eq!(Sorcerer::new().base_stat(), Sorcerer::new().stat);
+        assert_eq!(Knight::new().base_stat(), Knight::new().stat);
+    }
+
+    #[test]
+    fn test_mixed_party_behind_dyn() {
+        let party: Vec<Box<dyn Profession>> = vec![
+            Box::new(Warrior::new()),
+            Box::new(Knight::new()),
+            Box::new(Sorcerer::new()),
+        ];
+        let types: Vec<ProfessionType> = party.iter().map(|p| p.profession_type()).collect();
+        assert_eq!(
+            types,
+            vec![
+                ProfessionType::WarriorType,
+                ProfessionType::KnightType,
+                ProfessionType::SorcererType,
+            ]
+        );
+        assert!(party[0].effective_against(party[1].as_ref(), CounterMatrix::standard()));
+        assert!(party[1].effective_against(party[2].as_ref(), CounterMatrix::standard()));
+        assert!(party[2].effective_against(party[0].as_ref(), CounterMatrix::standard()));
+        assert!(party[0].suppressed_by(&party[2], CounterMatrix::standard()));
+    }
+
+    #[test]
+    fn test_boxed_profession_forwards() {
+        let prop = TestProp {};
+        let boxed: Box<dyn Profession> = Box::new(Sorcerer::new());
+        assert_eq!(boxed.base_stat(), Sorcerer::new().stat);
+        assert_eq!(boxed.attack_points(&prop), prop.get_stat().magic);
+        assert_eq!(boxed.defense_points(&prop), prop.get_stat().defense);
+        assert!(boxed.suppressed_by(&Knight::new(), CounterMatrix::standard()));
+        assert_eq!(boxed.growth(), Sorcerer::new().growth());
+        assert_eq!(boxed.skills(), Sorcerer::new().skills());
+    }
+
+    #[test]
+    fn test_profession_skills() {
+        let names: Vec<&str> = [
+            Warrior::new().skills(),
+            Sorcerer::new().skills(),
+            Knight::new().skills(),
+        ]
+        .iter()
+        .map(|skills| skills[0].name)
+        .collect();
+        assert_eq!(names, vec!["Cleave", "Fireball", "Shield Wall"]);
+        assert_eq!(Sorcerer::new().skills()[0].cost, SkillCost::Mana(20));
+        assert_eq!(Knight::new().skills()[0].target, SkillTarget::User);
+    }
+
+    #[test]
+    fn test_every_profession_has_a_skill() {
+        let party: Vec<Box<dyn Profession>> = vec![
+            Box::new(Warrior::new()),
+            Box::new(Sorcerer::new()),
+            Box::new(Knight::new()),
+            Box::new(Archer::new()),
+            Box::new(Cleric::new()),
+            Box::new(Rogue::new()),
+            Box::new(Paladin::new()),
+        ];
+        for profession in party {
+            assert!(
+                !profession.skills().is_empty(),
+                "{}",
+                profession.profession_type()
+            );
+        }
+    }
+
+    #[test]
+    fn test_profession_growth() {
+        assert_eq!(Warrior::new().growth().gain(2).attack, 3);
+        assert_eq!(Sorcerer::new().growth().gain(10).magic, 6);
+        let knight = Knight::new().growth();
+        assert_eq!(knight.gain(2).health, 14);
+        assert_eq!(knight.gain(20).health, 10);
+    }
+
+    #[test]
+    fn test_profession_type_display_round_trip() {
+        for profession in ProfessionType::iter() {
+            assert_eq!(profession.to_string().parse(), Ok(profession));
+        }
+        assert_eq!(ProfessionType::SorcererType.to_string(), "sorcerer");
+    }
+
+    #[test]
+    fn test_profession_type_from_str() {
+        assert_eq!(" WARRIOR ".parse(), Ok(ProfessionType::WarriorType));
+        assert_eq!(
+            "bard".parse::<ProfessionType>(),
+            Err(ParseProfessionTypeError("bard".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_profession_type_all_is_unique() {
+        let all: std::collections::HashSet<_> = ProfessionType::iter().collect();
+        assert_eq!(all.len(), ProfessionType::ALL.len());
     }
 
     #[test]
@@ -171,13 +832,21 @@ mod profession_tests {
         assert_eq!(knight.profession_type(), ProfessionType::KnightType);
     }
 
+    #[test]
+    fn test_effective_against_custom_counters() {
+        let counters = CounterMatrix::from_config("warrior sorcerer 2").unwrap();
+        assert!(Warrior::new().effective_against(&Sorcerer::new(), &counters));
+        assert!(!Warrior::new().effective_against(&Knight::new(), &counters));
+        assert!(Sorcerer::new().suppressed_by(&Warrior::new(), &counters));
+    }
+
     #[test]
     fn test_warrior_effective_against_type() {
         let warrior = Warrior::new();
         let knight = Knight::new();
         let sorcerer = Sorcerer::new();
-        assert_eq!(warrior.effective_against(&knight), true);
-        assert_eq!(warrior.effective_against(&sorcerer), false);
+        assert!(warrior.effective_against(&knight, CounterMatrix::standard()));
+        assert!(!warrior.effective_against(&sorcerer, CounterMatrix::standard()));
     }
 
     #[test]
@@ -185,8 +854,8 @@ mod profession_tests {
         let warrior = Warrior::new();
         let knight = Knight::new();
         let sorcerer = Sorcerer::new();
-        assert_eq!(warrior.suppressed_by(&knight), false);
-        assert_eq!(warrior.suppressed_by(&sorcerer), true);
+        assert!(!warrior.suppressed_by(&knight, CounterMatrix::standard()));
+        assert!(warrior.suppressed_by(&sorcerer, CounterMatrix::standard()));
     }
 
     #[test]
@@ -194,8 +863,8 @@ mod profession_tests {
         let warrior = Warrior::new();
         let knight = Knight::new();
         let sorcerer = Sorcerer::new();
-        assert_eq!(sorcerer.effective_against(&warrior), true);
-        assert_eq!(sorcerer.effective_against(&knight), false);
+        assert!(sorcerer.effective_against(&warrior, CounterMatrix::standard()));
+        assert!(!sorcerer.effective_against(&knight, CounterMatrix::standard()));
     }
 
     #[test]
@@ -203,8 +872,8 @@ mod profession_tests {
         let warrior = Warrior::new();
         let knight = Knight::new();
         let sorcerer = Sorcerer::new();
-        assert_eq!(sorcerer.suppressed_by(&knight), true);
-        assert_eq!(sorcerer.suppressed_by(&warrior), false);
+        assert!(sorcerer.suppressed_by(&knight, CounterMatrix::standard()));
+        assert!(!sorcerer.suppressed_by(&warrior, CounterMatrix::standard()));
     }
 
     #[test]
@@ -212,8 +881,8 @@ mod profession_tests {
         let warrior = Warrior::new();
         let knight = Knight::new();
         let sorcerer = Sorcerer::new();
-        assert_eq!(knight.effective_against(&sorcerer), true);
-        assert_eq!(knight.effective_against(&warrior), false);
+        assert!(knight.effective_against(&sorcerer, CounterMatrix::standard()));
+        assert!(!knight.effective_against(&warrior, CounterMatrix::standard()));
     }
 
     #[test]
@@ -221,61 +890,277 @@ mod profession_tests {
         let warrior = Warrior::new();
         let knight = Knight::new();
         let sorcerer = Sorcerer::new();
-        assert_eq!(knight.suppressed_by(&sorcerer), false);
-        assert_eq!(knight.suppressed_by(&warrior), true);
+        assert!(!knight.suppressed_by(&sorcerer, CounterMatrix::standard()));
+        assert!(knight.suppressed_by(&warrior, CounterMatrix::standard()));
     }
 
     struct TestProp;
 
     impl StatTrait for TestProp {
         fn get_stat(&self) -> Stat {
-            Stat{health: 10, attack: 11, defense: 12, magic: 13}
+            Stat {
+                health: 10,
+                attack: 11,
+                defense: 12,
+                magic: 13,
+            }
         }
+    }
 
-        fn set_stat(&self, stat: Stat) -> () {
-            todo!()
+    struct MaxProp;
+
+    impl StatTrait for MaxProp {
+        fn get_stat(&self) -> Stat {
+            Stat {
+                health: i32::MAX,
+                attack: i32::MAX,
+                defense: i32::MAX,
+                magic: i32::MAX,
+            }
         }
     }
 
+    #[test]
+    fn test_points_saturate() {
+        assert_eq!(Cleric::new().defense_points(&MaxProp), i32::MAX);
+        assert_eq!(Rogue::new().attack_points(&MaxProp), i32::MAX);
+        assert_eq!(Paladin::new().attack_points(&MaxProp), i32::MAX);
+        assert_eq!(Paladin::new().defense_points(&MaxProp), i32::MAX);
+    }
+
     #[test]
     fn test_sorcerer_attack_points() {
-        let prop = TestProp{};
+        let prop = TestProp {};
         let prof = Sorcerer::new();
         assert_eq!(prof.attack_points(&prop), prop.get_stat().magic)
     }
 
     #[test]
     fn test_sorcerer_defense_points() {
-        let prop = TestProp{};
+        let prop = TestProp {};
         let prof = Sorcerer::new();
         assert_eq!(prof.defense_points(&prop), prop.get_stat().defense)
     }
 
     #[test]
     fn test_knight_attack_points() {
-        let prop = TestProp{};
+        let prop = TestProp {};
         let prof = Knight::new();
         assert_eq!(prof.attack_points(&prop), prop.get_stat().attack)
     }
 
     #[test]
     fn test_knight_defense_points() {
-        let prop = TestProp{};
+        let prop = TestProp {};
         let prof = Knight::new();
         assert_eq!(prof.defense_points(&prop), prop.get_stat().defense)
     }
 
     #[test]
     fn test_warrior_attack_points() {
-        let prop = TestProp{};
+        let prop = TestProp {};
         let prof = Warrior::new();
         assert_eq!(prof.attack_points(&prop), prop.get_stat().attack)
     }
 
     #[test]
     fn test_warrior_defense_points() {
-        let prop = TestProp{};
+        let prop = TestProp {};
         let prof = Warrior::new();
         assert_eq!(prof.defense_points(&prop), prop.get_stat().defense)
     }
+
+    #[test]
+    fn test_new_archer_stat() {
+        let archer = Archer::new();
+        assert_eq!(
+            archer.stat,
+            Stat {
+                health: 75,
+                attack: 45,
+                defense: 20,
+                magic: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_new_cleric_stat() {
+        let cleric = Cleric::new();
+        assert_eq!(
+            cleric.stat,
+            Stat {
+                health: 80,
+                attack: 10,
+                defense: 30,
+                magic: 60
+            }
+        );
+    }
+
+    #[test]
+    fn test_new_rogue_stat() {
+        let rogue = Rogue::new();
+        assert_eq!(
+            rogue.stat,
+            Stat {
+                health: 70,
+                attack: 35,
+                defense: 25,
+                magic: 20
+            }
+        );
+    }
+
+    #[test]
+    fn test_new_paladin_stat() {
+        let paladin = Paladin::new();
+        assert_eq!(
+            paladin.stat,
+            Stat {
+                health: 100,
+                attack: 35,
+                defense: 45,
+                magic: 20
+            }
+        );
+    }
+
+    #[test]
+    fn test_archer_profession_type() {
+        assert_eq!(Archer::new().profession_type(), ProfessionType::ArcherType);
+    }
+
+    #[test]
+    fn test_cleric_profession_type() {
+        assert_eq!(Cleric::new().profession_type(), ProfessionType::ClericType);
+    }
+
+    #[test]
+    fn test_rogue_profession_type() {
+        assert_eq!(Rogue::new().profession_type(), ProfessionType::RogueType);
+    }
+
+    #[test]
+    fn test_paladin_profession_type() {
+        assert_eq!(
+            Paladin::new().profession_type(),
+            ProfessionType::PaladinType
+        );
+    }
+
+    #[test]
+    fn test_archer_effective_against_type() {
+        let archer = Archer::new();
+        assert!(archer.effective_against(&Sorcerer::new(), CounterMatrix::standard()));
+        assert!(!archer.effective_against(&Knight::new(), CounterMatrix::standard()));
+        assert!(!archer.effective_against(&Rogue::new(), CounterMatrix::standard()));
+    }
+
+    #[test]
+    fn test_archer_suppressed_by_type() {
+        let archer = Archer::new();
+        assert!(archer.suppressed_by(&Knight::new(), CounterMatrix::standard()));
+        assert!(archer.suppressed_by(&Rogue::new(), CounterMatrix::standard()));
+        assert!(!archer.suppressed_by(&Sorcerer::new(), CounterMatrix::standard()));
+    }
+
+    #[test]
+    fn test_cleric_effective_against_type() {
+        let cleric = Cleric::new();
+        assert!(cleric.effective_against(&Warrior::new(), CounterMatrix::standard()));
+        assert!(!cleric.effective_against(&Rogue::new(), CounterMatrix::standard()));
+    }
+
+    #[test]
+    fn test_cleric_suppressed_by_type() {
+        let cleric = Cleric::new();
+        assert!(cleric.suppressed_by(&Rogue::new(), CounterMatrix::standard()));
+        assert!(!cleric.suppressed_by(&Warrior::new(), CounterMatrix::standard()));
+    }
+
+    #[test]
+    fn test_rogue_effective_against_type() {
+        let rogue = Rogue::new();
+        assert!(rogue.effective_against(&Archer::new(), CounterMatrix::standard()));
+        assert!(rogue.effective_against(&Cleric::new(), CounterMatrix::standard()));
+        assert!(!rogue.effective_against(&Paladin::new(), CounterMatrix::standard()));
+    }
+
+    #[test]
+    fn test_rogue_suppressed_by_type() {
+        let rogue = Rogue::new();
+        assert!(rogue.suppressed_by(&Paladin::new(), CounterMatrix::standard()));
+        assert!(!rogue.suppressed_by(&Archer::new(), CounterMatrix::standard()));
+    }
+
+    #[test]
+    fn test_paladin_effective_against_type() {
+        let paladin = Paladin::new();
+        assert!(paladin.effective_against(&Rogue::new(), CounterMatrix::standard()));
+        assert!(!paladin.effective_against(&Warrior::new(), CounterMatrix::standard()));
+    }
+
+    #[test]
+    fn test_paladin_suppressed_by_type() {
+        let paladin = Paladin::new();
+        assert!(paladin.suppressed_by(&Warrior::new(), CounterMatrix::standard()));
+        assert!(!paladin.suppressed_by(&Rogue::new(), CounterMatrix::standard()));
+    }
+
+    #[test]
+    fn test_archer_attack_points() {
+        let prop = TestProp {};
+        let prof = Archer::new();
+        assert_eq!(prof.attack_points(&prop), prop.get_stat().attack)
+    }
+
+    #[test]
+    fn test_archer_defense_points() {
+        let prop = TestProp {};
+        let prof = Archer::new();
+        assert_eq!(prof.defense_points(&prop), prop.get_stat().defense)
+    }
+
+    #[test]
+    fn test_cleric_attack_points() {
+        let prop = TestProp {};
+        let prof = Cleric::new();
+        assert_eq!(prof.attack_points(&prop), 6)
+    }
+
+    #[test]
+    fn test_cleric_defense_points() {
+        let prop = TestProp {};
+        let prof = Cleric::new();
+        assert_eq!(prof.defense_points(&prop), 12 + 6)
+    }
+
+    #[test]
+    fn test_rogue_attack_points() {
+        let prop = TestProp {};
+        let prof = Rogue::new();
+        assert_eq!(prof.attack_points(&prop), 11 + 6)
+    }
+
+    #[test]
+    fn test_rogue_defense_points() {
+        let prop = TestProp {};
+        let prof = Rogue::new();
+        assert_eq!(prof.defense_points(&prop), prop.get_stat().defense)
+    }
+
+    #[test]
+    fn test_paladin_attack_points() {
+        let prop = TestProp {};
+        let prof = Paladin::new();
+        assert_eq!(prof.attack_points(&prop), 11 + 3)
+    }
+
+    #[test]
+    fn test_paladin_defense_points() {
+        let prop = TestProp {};
+        let prof = Paladin::new();
+        assert_eq!(prof.defense_points(&prop), 12 + 3)
+    }
 }
@@ -0,0 +1,202 @@
+use crate::game::stat::Stat;
+
+/// How a profession's `Stat` grows with each level.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GrowthCurve {
+    /// The same gain on every level up.
+    Linear(Stat),
+    /// `gains[0]` is the gain for reaching level 2, `gains[1]` for level 3 and
+    /// so on. Levels past the end of the table repeat the last gain.
+    Table(Vec<Stat>),
+}
+
+impl Default for GrowthCurve {
+    /// No growth at all.
+    fn default() -> Self {
+        GrowthCurve::Linear(Stat::default())
+    }
+}
+
+impl GrowthCurve {
+    /// Stat gained when reaching `level`, nothing for level 1 and below.
+    pub fn gain(&self, level: u32) -> Stat {
+        if level < 2 {
+            return Stat::default();
+        }
+        match self {
+            GrowthCurve::Linear(gain) => *gain,
+            GrowthCurve::Table(gains) => {
+                let index = (level - 2) as usize;
+                gains
+                    .get(index)
+                    .or_else(|| gains.last())
+                    .copied()
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    /// `base` grown from level 1 up to `level`.
+    /// # Examples
+    /// ```
+    /// use game::{GrowthCurve, Stat};
+    /// let curve = GrowthCurve::Linear(Stat {health: 10, attack: 2, defense: 1, magic: 0});
+    /// let base = Stat {health: 90, attack: 40, defense: 55, magic: 0};
+    /// assert_eq!(curve.stat_at(base, 3), Stat {health: 110, attack: 44, defense: 57, magic: 0});
+    /// ```
+    pub fn stat_at(&self, base: Stat, level: u32) -> Stat {
+        (2..=level).fold(base, |stat, level| stat.saturating_add(self.gain(level)))
+    }
+}
+
+/// A level reached through `Experience::gain`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LevelUp {
+    pub level: u32,
+    /// Stat gained on this level.
+    pub gain: Stat,
+    /// Base stat once the gain is applied.
+    pub stat: Stat,
+}
+
+/// Level and experience points collected towards the next level.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Experience {
+    level: u32,
+    xp: u32,
+}
+
+impl Default for Experience {
+    fn default() -> Self {
+        Self { level: 1, xp: 0 }
+    }
+}
+
+impl Experience {
+    pub const MAX_LEVEL: u32 = 99;
+
+    /// Level 1 without any experience.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    /// Experience collected since the last level up.
+    pub fn xp(&self) -> u32 {
+        self.xp
+    }
+
+    /// Experience needed to go from `level` to the next one.
+    pub fn xp_to_next(level: u32) -> u32 {
+        level.saturating_mul(100)
+    }
+
+    /// Add experience and return every level reached, in order.
+    ///
+    /// Experience past `MAX_LEVEL` is dropped.
+    /// # Examples
+    /// ```
+    /// use game::Experience;
+    /// let mut experience = Experience::new();
+    /// assert_eq!(experience.gain(350), vec![2, 3]);
+    /// assert_eq!(experience.level(), 3);
+    /// assert_eq!(experience.xp(), 50);
+    /// ```
+    pub fn gain(&mut self, amount: u32) -> Vec<u32> {
+        let mut reached = Vec::new();
+        if self.level >= Self::MAX_LEVEL {
+            return reached;
+        }
+        self.xp = self.xp.saturating_add(amount);
+        while self.level < Self::MAX_LEVEL && self.xp >= Self::xp_to_next(self.level) {
+            self.xp -= Self::xp_to_next(self.level);
+            self.level += 1;
+            reached.push(self.level);
+        }
+        if self.level == Self::MAX_LEVEL {
+            self.xp = 0;
+        }
+        reached
+    }
+}
+
+#[cfg(test)]
+mod progression_tests {
+    use super::*;
+
+    fn gain(health: i32) -> Stat {
+        Stat {
+            health,
+            attack: 1,
+            defense: 1,
+            magic: 0,
+        }
+    }
+
+    #[test]
+    fn test_linear_gain() {
+        let curve = GrowthCurve::Linear(gain(5));
+        assert_eq!(curve.gain(1), Stat::default());
+        assert_eq!(curve.gain(2), gain(5));
+        assert_eq!(curve.gain(50), gain(5));
+    }
+
+    #[test]
+    fn test_table_gain_repeats_last_entry() {
+        let curve = GrowthCurve::Table(vec![gain(10), gain(8)]);
+        assert_eq!(curve.gain(2), gain(10));
+        assert_eq!(curve.gain(3), gain(8));
+        assert_eq!(curve.gain(4), gain(8));
+    }
+
+    #[test]
+    fn test_empty_table_has_no_growth() {
+        assert_eq!(GrowthCurve::Table(vec![]).gain(5), Stat::default());
+        assert_eq!(GrowthCurve::default().stat_at(gain(10), 10), gain(10));
+    }
+
+    #[test]
+    fn test_stat_at() {
+        let curve = GrowthCurve::Table(vec![gain(10), gain(8)]);
+        let base = Stat {
+            health: 100,
+            attack: 0,
+            defense: 0,
+            magic: 0,
+        };
+        assert_eq!(curve.stat_at(base, 1), base);
+        assert_eq!(
+            curve.stat_at(base, 4),
+            Stat {
+                health: 126,
+                attack: 3,
+                defense: 3,
+                magic: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_experience_gain_below_threshold() {
+        let mut experience = Experience::new();
+        assert!(experience.gain(99).is_empty());
+        assert_eq!(experience.level(), 1);
+        assert_eq!(experience.xp(), 99);
+        assert_eq!(experience.gain(1), vec![2]);
+        assert_eq!(experience.xp(), 0);
+    }
+
+    #[test]
+    fn test_experience_caps_at_max_level() {
+        let mut experience = Experience::new();
+        let reached = experience.gain(u32::MAX);
+        assert_eq!(reached.len() as u32, Experience::MAX_LEVEL - 1);
+        assert_eq!(experience.level(), Experience::MAX_LEVEL);
+        assert_eq!(experience.xp(), 0);
+        assert!(experience.gain(1000).is_empty());
+        assert_eq!(experience.xp(), 0);
+    }
+}
@@ -0,0 +1,613 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+use std::sync::OnceLock;
+
+use crate::game::profession::{Profession, ProfessionType, Sorcerer, Warrior};
+use crate::game::progression::GrowthCurve;
+use crate::game::skill::Skill;
+use crate::game::stat::{Stat, StatTrait};
+
+/// An edge of the `PromotionTree`: class `from` may become class `to` once
+/// it reaches `level`. Classes are named by `Profession::class_name`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Promotion {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub level: u32,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PromotionError {
+    /// Adding the promotion would let a class promote back into itself.
+    Cycle {
+        from: &'static str,
+        to: &'static str,
+    },
+    /// The class can already be reached through another promotion.
+    AlreadyReachable {
+        to: &'static str,
+    },
+    /// No promotion from the first class into the second.
+    NotAvailable {
+        from: &'static str,
+        to: &'static str,
+    },
+    LevelTooLow {
+        required: u32,
+        level: u32,
+    },
+    /// A promoted class must keep the `ProfessionType` of the class it came from.
+    ProfessionMismatch {
+        from: ProfessionType,
+        to: ProfessionType,
+    },
+}
+
+impl fmt::Display for PromotionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PromotionError::Cycle { from, to } => {
+                write!(f, "promoting {} into {} would create a cycle", from, to)
+            }
+            PromotionError::AlreadyReachable { to } => {
+                write!(f, "{} already has a promotion leading to it", to)
+            }
+            PromotionError::NotAvailable { from, to } => {
+                write!(f, "{} can't be promoted into {}", from, to)
+            }
+            PromotionError::LevelTooLow { required, level } => {
+                write!(
+                    f,
+                    "promotion requires level {}, got level {}",
+                    required, level
+                )
+            }
+            PromotionError::ProfessionMismatch { from, to } => {
+                write!(f, "a {} can't be promoted into a {}", from, to)
+            }
+        }
+    }
+}
+
+impl Error for PromotionError {}
+
+/// Which classes can be promoted into which.
+///
+/// Every class has at most one promotion leading to it and can never be
+/// promoted back into one of its ancestors, so the promotions always form a
+/// forest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PromotionTree {
+    promotions: Vec<Promotion>,
+}
+
+impl PromotionTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Warrior into Berserker or Gladiator, Sorcerer into Archmage or
+    /// Necromancer, all at level 20.
+    pub fn standard() -> &'static PromotionTree {
+        static STANDARD: OnceLock<PromotionTree> = OnceLock::new();
+        STANDARD.get_or_init(|| {
+            let mut tree = Self::new();
+            for (from, to) in [
+                ("warrior", "berserker"),
+                ("warrior", "gladiator"),
+                ("sorcerer", "archmage"),
+                ("sorcerer", "necromancer"),
+            ] {
+                tree.add(Promotion {
+                    from,
+                    to,
+                    level: 20,
+                })
+                .expect("the standard promotions form a tree");
+            }
+            tree
+        })
+    }
+
+    /// Add a promotion, rejecting any that would break the tree.
+    /// # Examples
+    /// ```
+    /// use game::{Promotion, PromotionError, PromotionTree};
+    /// let mut tree = PromotionTree::new();
+    /// tree.add(Promotion {from: "warrior", to: "berserker", level: 20}).unwrap();
+    /// assert_eq!(
+    ///     tree.add(Promotion {from: "berserker", to: "warrior", level: 40}),
+    ///     Err(PromotionError::Cycle {from: "berserker", to: "warrior"})
+    /// );
+    /// ```
+    pub fn add(&mut self, promotion: Promotion) -> Result<(), PromotionError> {
+        let Promotion { from, to, .. } = promotion;
+        if from == to || self.ancestors(from).any(|ancestor| ancestor == to) {
+            return Err(PromotionError::Cycle { from, to });
+        }
+        if self.parent(to).is_some() {
+            return Err(PromotionError::AlreadyReachable { to });
+        }
+        self.promotions.push(promotion);
+        Ok(())
+    }
+
+    /// Every promotion out of `class`, whatever the level.
+    pub fn promotions_from<'a>(&'a self, class: &'a str) -> impl Iterator<Item = &'a Promotion> {
+        self.promotions
+            .iter()
+            .filter(move |promotion| promotion.from == class)
+    }
+
+    /// Promotions out of `class` that are unlocked at `level`.
+    pub fn available<'a>(
+        &'a self,
+        class: &'a str,
+        level: u32,
+    ) -> impl Iterator<Item = &'a Promotion> {
+        self.promotions_from(class)
+            .filter(move |promotion| promotion.level <= level)
+    }
+
+    /// Level required to promote `from` into `to`.
+    pub fn requirement(&self, from: &str, to: &str) -> Option<u32> {
+        self.promotions_from(from)
+            .find(|promotion| promotion.to == to)
+            .map(|promotion| promotion.level)
+    }
+
+    /// The class `class` is promoted from, if any.
+    pub fn parent(&self, class: &str) -> Option<&'static str> {
+        self.promotions
+            .iter()
+            .find(|promotion| promotion.to == class)
+            .map(|promotion| promotion.from)
+    }
+
+    /// Classes `class` was promoted through, closest first.
+    pub fn ancestors<'a>(&'a self, class: &'a str) -> impl Iterator<Item = &'static str> + 'a {
+        std::iter::successors(self.parent(class), move |class| self.parent(class))
+    }
+
+    /// Check that a `from` class at `level` may promote into `to`.
+    pub fn check(
+        &self,
+        from: &'static str,
+        to: &'static str,
+        level: u32,
+    ) -> Result<(), PromotionError> {
+        match self.requirement(from, to) {
+            None => Err(PromotionError::NotAvailable { from, to }),
+            Some(required) if level < required => {
+                Err(PromotionError::LevelTooLow { required, level })
+            }
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+/// A Warrior who trades caution for raw strength.
+pub struct Berserker {
+    base: Warrior,
+}
+
+impl Berserker {
+    pub fn new() -> Self {
+        Self {
+            base: Warrior::new(),
+        }
+    }
+}
+
+impl Default for Berserker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profession for Berserker {
+    fn profession_type(&self) -> ProfessionType {
+        self.base.profession_type()
+    }
+
+    fn class_name(&self) -> &'static str {
+        "berserker"
+    }
+
+    fn base_stat(&self) -> Stat {
+        self.base.base_stat()
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        GrowthCurve::Linear(Stat {
+            health: 12,
+            attack: 5,
+            defense: 2,
+            magic: 0,
+        })
+    }
+
+    fn skills(&self) -> Vec<Skill> {
+        self.base.skills()
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        let attack = stat_trait.get_stat().attack;
+        attack.saturating_add(attack / 4)
+    }
+
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        self.base.defense_points(stat_trait)
+    }
+}
+
+/// A Warrior trained to fight behind a shield, defense adds to its attack.
+pub struct Gladiator {
+    base: Warrior,
+}
+
+impl Gladiator {
+    pub fn new() -> Self {
+        Self {
+            base: Warrior::new(),
+        }
+    }
+}
+
+impl Default for Gladiator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profession for Gladiator {
+    fn profession_type(&self) -> ProfessionType {
+        self.base.profession_type()
+    }
+
+    fn class_name(&self) -> &'static str {
+        "gladiator"
+    }
+
+    fn base_stat(&self) -> Stat {
+        self.base.base_stat()
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        GrowthCurve::Linear(Stat {
+            health: 10,
+            attack: 3,
+            defense: 5,
+            magic: 0,
+        })
+    }
+
+    fn skills(&self) -> Vec<Skill> {
+        self.base.skills()
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        let stat = stat_trait.get_stat();
+        stat.attack.saturating_add(stat.defense / 4)
+    }
+
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        self.base.defense_points(stat_trait)
+    }
+}
+
+/// A Sorcerer mastering raw magic.
+pub struct Archmage {
+    base: Sorcerer,
+}
+
+impl Archmage {
+    pub fn new() -> Self {
+        Self {
+            base: Sorcerer::new(),
+        }
+    }
+}
+
+impl Default for Archmage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profession for Archmage {
+    fn profession_type(&self) -> ProfessionType {
+        self.base.profession_type()
+    }
+
+    fn class_name(&self) -> &'static str {
+        "archmage"
+    }
+
+    fn base_stat(&self) -> Stat {
+        self.base.base_stat()
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        GrowthCurve::Linear(Stat {
+            health: 5,
+            attack: 0,
+            defense: 1,
+            magic: 9,
+        })
+    }
+
+    fn skills(&self) -> Vec<Skill> {
+        self.base.skills()
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        let magic = stat_trait.get_stat().magic;
+        magic.saturating_add(magic / 4)
+    }
+
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        self.base.defense_points(stat_trait)
+    }
+}
+
+/// A Sorcerer drawing on life force, a tenth of its health adds to its attack.
+pub struct Necromancer {
+    base: Sorcerer,
+}
+
+impl Necromancer {
+    pub fn new() -> Self {
+        Self {
+            base: Sorcerer::new(),
+        }
+    }
+}
+
+impl Default for Necromancer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profession for Necromancer {
+    fn profession_type(&self) -> ProfessionType {
+        self.base.profession_type()
+    }
+
+    fn class_name(&self) -> &'static str {
+        "necromancer"
+    }
+
+    fn base_stat(&self) -> Stat {
+        self.base.base_stat()
+    }
+
+    fn growth(&self) -> GrowthCurve {
+        GrowthCurve::Linear(Stat {
+            health: 9,
+            attack: 0,
+            defense: 2,
+            magic: 6,
+        })
+    }
+
+    fn skills(&self) -> Vec<Skill> {
+        self.base.skills()
+    }
+
+    fn attack_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        let stat = stat_trait.get_stat();
+        stat.magic.saturating_add(stat.health / 10)
+    }
+
+    fn defense_points(&self, stat_trait: &dyn StatTrait) -> i32 {
+        self.base.defense_points(stat_trait)
+    }
+}
+
+#[cfg(test)]
+mod promotion_tests {
+    use super::*;
+    use crate::game::counter::CounterMatrix;
+    use crate::game::profession::Knight;
+
+    struct TestProp;
+
+    impl StatTrait for TestProp {
+        fn get_stat(&self) -> Stat {
+            Stat {
+                health: 100,
+                attack: 40,
+                defense: 20,
+                magic: 60,
+            }
+        }
+    }
+
+    #[test]
+    fn test_standard_tree_promotions() {
+        let tree = PromotionTree::standard();
+        let warrior: Vec<_> = tree.promotions_from("warrior").map(|p| p.to).collect();
+        assert_eq!(warrior, vec!["berserker", "gladiator"]);
+        let sorcerer: Vec<_> = tree.promotions_from("sorcerer").map(|p| p.to).collect();
+        assert_eq!(sorcerer, vec!["archmage", "necromancer"]);
+        assert_eq!(tree.promotions_from("knight").count(), 0);
+        assert_eq!(tree.requirement("warrior", "berserker"), Some(20));
+        assert_eq!(tree.requirement("warrior", "archmage"), None);
+    }
+
+    #[test]
+    fn test_available_depends_on_level() {
+        let tree = PromotionTree::standard();
+        assert_eq!(tree.available("sorcerer", 19).count(), 0);
+        assert_eq!(tree.available("sorcerer", 20).count(), 2);
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let mut tree = PromotionTree::standard().clone();
+        tree.add(Promotion {
+            from: "berserker",
+            to: "warlord",
+            level: 40,
+        })
+        .unwrap();
+        assert_eq!(tree.parent("warlord"), Some("berserker"));
+        assert_eq!(
+            tree.ancestors("warlord").collect::<Vec<_>>(),
+            vec!["berserker", "warrior"]
+        );
+        assert_eq!(tree.ancestors("warrior").count(), 0);
+    }
+
+    #[test]
+    fn test_add_rejects_cycles() {
+        let mut tree = PromotionTree::standard().clone();
+        tree.add(Promotion {
+            from: "berserker",
+            to: "warlord",
+            level: 40,
+        })
+        .unwrap();
+        assert_eq!(
+            tree.add(Promotion {
+                from: "warlord",
+                to: "warrior",
+                level: 60
+            }),
+            Err(PromotionError::Cycle {
+                from: "warlord",
+                to: "warrior"
+            })
+        );
+        assert_eq!(
+            tree.add(Promotion {
+                from: "knight",
+                to: "knight",
+                level: 1
+            }),
+            Err(PromotionError::Cycle {
+                from: "knight",
+                to: "knight"
+            })
+        );
+    }
+
+    #[test]
+    fn test_add_rejects_second_parent() {
+        let mut tree = PromotionTree::standard().clone();
+        assert_eq!(
+            tree.add(Promotion {
+                from: "knight",
+                to: "gladiator",
+                level: 20
+            }),
+            Err(PromotionError::AlreadyReachable { to: "gladiator" })
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let tree = PromotionTree::standard();
+        assert_eq!(tree.check("warrior", "gladiator", 25), Ok(()));
+        assert_eq!(
+            tree.check("warrior", "gladiator", 5),
+            Err(PromotionError::LevelTooLow {
+                required: 20,
+                level: 5
+            })
+        );
+        assert_eq!(
+            tree.check("knight", "gladiator", 25),
+            Err(PromotionError::NotAvailable {
+                from: "knight",
+                to: "gladiator"
+            })
+        );
+    }
+
+    #[test]
+    fn test_promoted_classes_keep_counters() {
+        let knight = Knight::new();
+        assert_eq!(
+            Berserker::new().profession_type(),
+            ProfessionType::WarriorType
+        );
+        assert!(Berserker::new().effective_against(&knight, CounterMatrix::standard()));
+        assert!(Gladiator::new().effective_against(&knight, CounterMatrix::standard()));
+        assert!(Archmage::new().suppressed_by(&knight, CounterMatrix::standard()));
+        assert!(Necromancer::new().suppressed_by(&knight, CounterMatrix::standard()));
+    }
+
+    #[test]
+    fn test_promoted_classes_inherit_base_stat_and_skills() {
+        assert_eq!(Berserker::new().base_stat(), Warrior::new().stat());
+        assert_eq!(Gladiator::new().skills(), Warrior::new().skills());
+        assert_eq!(Archmage::new().base_stat(), Sorcerer::new().stat());
+        assert_eq!(Necromancer::new().skills(), Sorcerer::new().skills());
+    }
+
+    #[test]
+    fn test_promoted_classes_override_growth() {
+        assert_ne!(Berserker::new().growth(), Warrior::new().growth());
+        assert_ne!(Gladiator::new().growth(), Warrior::new().growth());
+        assert_ne!(Archmage::new().growth(), Sorcerer::new().growth());
+        assert_ne!(Necromancer::new().growth(), Sorcerer::new().growth());
+    }
+
+    #[test]
+    fn test_class_names() {
+        assert_eq!(Warrior::new().class_name(), "warrior");
+        assert_eq!(Berserker::new().class_name(), "berserker");
+        assert_eq!(Gladiator::new().class_name(), "gladiator");
+        assert_eq!(Archmage::new().class_name(), "archmage");
+        assert_eq!(Necromancer::new().class_name(), "necromancer");
+    }
+
+    #[test]
+    fn test_berserker_attack_points() {
+        assert_eq!(Berserker::new().attack_points(&TestProp), 50);
+        assert_eq!(Berserker::new().defense_points(&TestProp), 20);
+    }
+
+    #[test]
+    fn test_gladiator_attack_points() {
+        assert_eq!(Gladiator::new().attack_points(&TestProp), 45);
+        assert_eq!(Gladiator::new().defense_points(&TestProp), 20);
+    }
+
+    #[test]
+    fn test_archmage_attack_points() {
+        assert_eq!(Archmage::new().attack_points(&TestProp), 75);
+        assert_eq!(Archmage::new().defense_points(&TestProp), 20);
+    }
+
+    #[test]
+    fn test_necromancer_attack_points() {
+        assert_eq!(Necromancer::new().attack_points(&TestProp), 70);
+        assert_eq!(Necromancer::new().defense_points(&TestProp), 20);
+    }
+
+    struct MaxProp;
+
+    impl StatTrait for MaxProp {
+        fn get_stat(&self) -> Stat {
+            Stat {
+                health: i32::MAX,
+                attack: i32::MAX,
+                defense: i32::MAX,
+                magic: i32::MAX,
+            }
+        }
+    }
+
+    #[test]
+    fn test_promoted_points_saturate() {
+        assert_eq!(Berserker::new().attack_points(&MaxProp), i32::MAX);
+        assert_eq!(Gladiator::new().attack_points(&MaxProp), i32::MAX);
+        assert_eq!(Archmage::new().attack_points(&MaxProp), i32::MAX);
+        assert_eq!(Necromancer::new().attack_points(&MaxProp), i32::MAX);
+    }
+}
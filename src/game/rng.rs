@@ -0,0 +1,118 @@
+/// Source of randomness for loot and affixes.
+///
+/// Only `next_u64` is required, the other methods build on it. Pass a
+/// `SeededRng` to get the same rolls every time.
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+
+    /// A number in `0..bound`, always 0 when `bound` is 0.
+    fn below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            return 0;
+        }
+        // Reject the top partial range so every value is equally likely.
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+
+    /// A number in `low..=high`, `low` when the range is empty.
+    /// # Examples
+    /// ```
+    /// use game::{Rng, SeededRng};
+    /// let mut rng = SeededRng::new(7);
+    /// let roll = rng.range(1, 6);
+    /// assert!((1..=6).contains(&roll));
+    /// assert_eq!(rng.range(3, 3), 3);
+    /// ```
+    fn range(&mut self, low: i32, high: i32) -> i32 {
+        if high <= low {
+            return low;
+        }
+        let span = (high as i64 - low as i64) as u64 + 1;
+        (low as i64 + self.below(span) as i64) as i32
+    }
+
+    /// `true` with the given probability, between 0.0 and 1.0.
+    fn chance(&mut self, probability: f32) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < probability as f64
+    }
+}
+
+impl<R: Rng + ?Sized> Rng for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// A small deterministic generator (SplitMix64).
+///
+/// The sequence only depends on the seed, so rolls can be replayed and
+/// tested. Not suited for anything security related.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl Rng for SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod rng_tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut first = SeededRng::new(42);
+        let mut second = SeededRng::new(42);
+        for _ in 0..10 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+        assert_ne!(SeededRng::new(1).next_u64(), SeededRng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_range_stays_in_bounds() {
+        let mut rng = SeededRng::new(0);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3, 3)));
+        }
+        assert_eq!(rng.range(5, 1), 5);
+        assert_eq!(rng.below(0), 0);
+    }
+
+    #[test]
+    fn test_range_covers_every_value() {
+        let mut rng = SeededRng::new(0);
+        let mut seen = [false; 4];
+        for _ in 0..200 {
+            seen[rng.range(0, 3) as usize] = true;
+        }
+        assert_eq!(seen, [true; 4]);
+    }
+
+    #[test]
+    fn test_chance_bounds() {
+        let mut rng = SeededRng::new(9);
+        assert!((0..100).all(|_| !rng.chance(0.0)));
+        assert!((0..100).all(|_| rng.chance(1.0)));
+    }
+}
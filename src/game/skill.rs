@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use crate::game::stat::Stat;
+use crate::game::status::StatusEffect;
+use crate::game::vitals::Vitals;
+
+/// What using a `Skill` costs.
+///
+/// Casters pay with mana, whose maximum is `Stat::magic`. Fighters without
+/// magic pay with stamina, taken from their health (`Stat::health`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SkillCost {
+    Free,
+    Mana(i32),
+    Health(i32),
+}
+
+/// Who a `Skill` affects.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SkillTarget {
+    /// The character using the skill.
+    User,
+    Opponent,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SkillEffect {
+    /// An attack with the user's attack points scaled by `power`.
+    Damage { power: f32 },
+    /// Restore health worth the user's magic scaled by `power`.
+    Heal { power: f32 },
+    /// Shift the target's stat by this delta until the end of the fight.
+    ModifyStat(Stat),
+    /// Attach a status effect to the target.
+    Status(StatusEffect),
+}
+
+/// An ability of a `Profession`, see `Profession::skills`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Skill {
+    pub name: &'static str,
+    pub cost: SkillCost,
+    /// Turns of the user to wait before the skill can be used again.
+    pub cooldown: u32,
+    pub target: SkillTarget,
+    pub effect: SkillEffect,
+}
+
+impl Skill {
+    /// Vitals after paying the cost, `None` if they can't cover it.
+    ///
+    /// A health cost can't be paid with the last point of health.
+    /// # Examples
+    /// ```
+    /// use game::{Skill, SkillCost, SkillEffect, SkillTarget, Vitals};
+    /// let skill = Skill {
+    ///     name: "Fireball",
+    ///     cost: SkillCost::Mana(20),
+    ///     cooldown: 2,
+    ///     target: SkillTarget::Opponent,
+    ///     effect: SkillEffect::Damage {power: 1.5},
+    /// };
+    /// assert_eq!(skill.pay(Vitals {health: 70, mana: 50}), Some(Vitals {health: 70, mana: 30}));
+    /// assert_eq!(skill.pay(Vitals {health: 70, mana: 10}), None);
+    /// ```
+    pub fn pay(&self, vitals: Vitals) -> Option<Vitals> {
+        match self.cost {
+            SkillCost::Free => Some(vitals),
+            SkillCost::Mana(amount) => vitals.spend_mana(amount),
+            SkillCost::Health(amount) if vitals.health > amount.max(0) => {
+                Some(vitals.damage(amount))
+            }
+            SkillCost::Health(_) => None,
+        }
+    }
+
+    pub fn can_afford(&self, vitals: Vitals) -> bool {
+        self.pay(vitals).is_some()
+    }
+}
+
+/// Remaining cooldowns of the skills used by one character, keyed by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cooldowns {
+    remaining: HashMap<&'static str, u32>,
+}
+
+impl Cooldowns {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turns left before the skill called `name` is ready.
+    pub fn remaining(&self, name: &str) -> u32 {
+        self.remaining.get(name).copied().unwrap_or(0)
+    }
+
+    pub fn is_ready(&self, skill: &Skill) -> bool {
+        self.remaining(skill.name) == 0
+    }
+
+    /// Start the cooldown of a skill that was just used.
+    pub fn trigger(&mut self, skill: &Skill) {
+        if skill.cooldown > 0 {
+            self.remaining.insert(skill.name, skill.cooldown);
+        }
+    }
+
+    /// Count down every cooldown by one turn.
+    pub fn tick(&mut self) {
+        self.remaining.retain(|_, turns| {
+            *turns -= 1;
+            *turns > 0
+        });
+    }
+}
+
+#[cfg(test)]
+mod skill_tests {
+    use super::*;
+
+    fn skill(cost: SkillCost, cooldown: u32) -> Skill {
+        Skill {
+            name: "Test",
+            cost,
+            cooldown,
+            target: SkillTarget::Opponent,
+            effect: SkillEffect::Damage { power: 1.0 },
+        }
+    }
+
+    #[test]
+    fn test_pay_free() {
+        let vitals = Vitals { health: 1, mana: 0 };
+        assert_eq!(skill(SkillCost::Free, 0).pay(vitals), Some(vitals));
+    }
+
+    #[test]
+    fn test_pay_mana() {
+        let skill = skill(SkillCost::Mana(10), 0);
+        assert_eq!(
+            skill.pay(Vitals {
+                health: 5,
+                mana: 10
+            }),
+            Some(Vitals { health: 5, mana: 0 })
+        );
+        assert!(!skill.can_afford(Vitals { health: 5, mana: 9 }));
+    }
+
+    #[test]
+    fn test_pay_health_keeps_user_alive() {
+        let skill = skill(SkillCost::Health(10), 0);
+        assert_eq!(
+            skill.pay(Vitals {
+                health: 11,
+                mana: 0
+            }),
+            Some(Vitals { health: 1, mana: 0 })
+        );
+        assert_eq!(
+            skill.pay(Vitals {
+                health: 10,
+                mana: 0
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cooldowns() {
+        let skill = skill(SkillCost::Free, 2);
+        let mut cooldowns = Cooldowns::new();
+        assert!(cooldowns.is_ready(&skill));
+        cooldowns.trigger(&skill);
+        assert_eq!(cooldowns.remaining("Test"), 2);
+        cooldowns.tick();
+        assert!(!cooldowns.is_ready(&skill));
+        cooldowns.tick();
+        assert!(cooldowns.is_ready(&skill));
+        cooldowns.tick();
+        assert_eq!(cooldowns.remaining("Test"), 0);
+    }
+
+    #[test]
+    fn test_zero_cooldown_is_always_ready() {
+        let skill = skill(SkillCost::Free, 0);
+        let mut cooldowns = Cooldowns::new();
+        cooldowns.trigger(&skill);
+        assert!(cooldowns.is_ready(&skill));
+    }
+}
@@ -1,8 +1,14 @@
+use std::error::Error;
 use std::fmt;
 use std::fmt::Formatter;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// Base attributes of an entity.
+///
+/// `health` is the maximum health and `magic` doubles as the maximum mana,
+/// the current values live in `Vitals`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
 pub struct Stat {
     pub health: i32,
     pub attack: i32,
@@ -10,9 +16,271 @@ pub struct Stat {
     pub magic: i32,
 }
 
+/// Read access to the `Stat` of an entity.
 pub trait StatTrait {
     fn get_stat(&self) -> Stat;
-    fn set_stat(&self, stat: Stat) -> ();
+}
+
+/// Write access to the `Stat` of an entity.
+pub trait StatMutTrait: StatTrait {
+    fn set_stat(&mut self, stat: Stat);
+}
+
+/// Write access through a shared reference, for entities keeping their
+/// `Stat` behind a `Cell` or `RefCell`. Wrap them in `Interior` to use them
+/// wherever a `StatMutTrait` is expected.
+pub trait InteriorStatTrait: StatTrait {
+    fn set_stat(&self, stat: Stat);
+}
+
+/// Adapter exposing an interior-mutable entity as `StatMutTrait` (and
+/// `VitalsMutTrait`), the writes go through the shared reference.
+/// # Examples
+/// ```
+/// use std::cell::Cell;
+/// use game::{Interior, InteriorStatTrait, Stat, StatMutTrait, StatTrait};
+/// struct Legacy(Cell<Stat>);
+/// impl StatTrait for Legacy {
+///     fn get_stat(&self) -> Stat { self.0.get() }
+/// }
+/// impl InteriorStatTrait for Legacy {
+///     fn set_stat(&self, stat: Stat) { self.0.set(stat) }
+/// }
+/// let legacy = Legacy(Cell::new(Stat::default()));
+/// let stat = Stat {health: 1, attack: 2, defense: 3, magic: 4};
+/// StatMutTrait::set_stat(&mut Interior(&legacy), stat);
+/// assert_eq!(legacy.get_stat(), stat);
+/// ```
+pub struct Interior<'a, T: ?Sized>(pub &'a T);
+
+impl<T: StatTrait + ?Sized> StatTrait for Interior<'_, T> {
+    fn get_stat(&self) -> Stat {
+        self.0.get_stat()
+    }
+}
+
+impl<T: InteriorStatTrait + ?Sized> StatMutTrait for Interior<'_, T> {
+    fn set_stat(&mut self, stat: Stat) {
+        InteriorStatTrait::set_stat(self.0, stat);
+    }
+}
+
+/// Names a single member of `Stat`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum StatField {
+    Health,
+    Attack,
+    Defense,
+    Magic,
+}
+
+impl StatField {
+    pub const ALL: [StatField; 4] = [
+        StatField::Health,
+        StatField::Attack,
+        StatField::Defense,
+        StatField::Magic,
+    ];
+}
+
+impl Stat {
+    /// Read a single member.
+    /// # Examples
+    /// ```
+    /// use game::{Stat, StatField};
+    /// let stat = Stat {health: 10, attack: 20, defense: 30, magic: 40};
+    /// assert_eq!(stat.get(StatField::Defense), 30);
+    /// ```
+    pub fn get(&self, field: StatField) -> i32 {
+        match field {
+            StatField::Health => self.health,
+            StatField::Attack => self.attack,
+            StatField::Defense => self.defense,
+            StatField::Magic => self.magic,
+        }
+    }
+
+    /// Overwrite a single member.
+    pub fn set(&mut self, field: StatField, value: i32) {
+        match field {
+            StatField::Health => self.health = value,
+            StatField::Attack => self.attack = value,
+            StatField::Defense => self.defense = value,
+            StatField::Magic => self.magic = value,
+        }
+    }
+
+    /// Add two `Stat`, returning `None` if any member overflows.
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stat = Stat {health: 10, attack: 10, defense: 10, magic: 10};
+    /// let max = Stat {health: i32::MAX, attack: 0, defense: 0, magic: 0};
+    /// assert_eq!(stat.checked_add(stat), Some(Stat {health: 20, attack: 20, defense: 20, magic: 20}));
+    /// assert_eq!(stat.checked_add(max), None);
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            health: self.health.checked_add(rhs.health)?,
+            attack: self.attack.checked_add(rhs.attack)?,
+            defense: self.defense.checked_add(rhs.defense)?,
+            magic: self.magic.checked_add(rhs.magic)?,
+        })
+    }
+
+    /// Subtract two `Stat`, returning `None` if any member overflows.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            health: self.health.checked_sub(rhs.health)?,
+            attack: self.attack.checked_sub(rhs.attack)?,
+            defense: self.defense.checked_sub(rhs.defense)?,
+            magic: self.magic.checked_sub(rhs.magic)?,
+        })
+    }
+
+    /// Add two `Stat`, saturating each member at the `i32` bounds.
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stat = Stat {health: i32::MAX, attack: 10, defense: 10, magic: 10};
+    /// let stat2 = Stat {health: 10, attack: 10, defense: 10, magic: 10};
+    /// assert_eq!(stat.saturating_add(stat2), Stat {health: i32::MAX, attack: 20, defense: 20, magic: 20});
+    /// ```
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            health: self.health.saturating_add(rhs.health),
+            attack: self.attack.saturating_add(rhs.attack),
+            defense: self.defense.saturating_add(rhs.defense),
+            magic: self.magic.saturating_add(rhs.magic),
+        }
+    }
+
+    /// Subtract two `Stat`, saturating each member at the `i32` bounds.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            health: self.health.saturating_sub(rhs.health),
+            attack: self.attack.saturating_sub(rhs.attack),
+            defense: self.defense.saturating_sub(rhs.defense),
+            magic: self.magic.saturating_sub(rhs.magic),
+        }
+    }
+
+    /// Scale every member by an integer factor, saturating at the `i32` bounds.
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stat = Stat {health: i32::MAX, attack: -10, defense: 10, magic: 0};
+    /// assert_eq!(stat.saturating_mul(2), Stat {health: i32::MAX, attack: -20, defense: 20, magic: 0});
+    /// ```
+    pub fn saturating_mul(self, rhs: i32) -> Self {
+        Self {
+            health: self.health.saturating_mul(rhs),
+            attack: self.attack.saturating_mul(rhs),
+            defense: self.defense.saturating_mul(rhs),
+            magic: self.magic.saturating_mul(rhs),
+        }
+    }
+
+    /// Add two `Stat`, wrapping each member around the `i32` bounds.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            health: self.health.wrapping_add(rhs.health),
+            attack: self.attack.wrapping_add(rhs.attack),
+            defense: self.defense.wrapping_add(rhs.defense),
+            magic: self.magic.wrapping_add(rhs.magic),
+        }
+    }
+
+    /// Subtract two `Stat`, wrapping each member around the `i32` bounds.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            health: self.health.wrapping_sub(rhs.health),
+            attack: self.attack.wrapping_sub(rhs.attack),
+            defense: self.defense.wrapping_sub(rhs.defense),
+            magic: self.magic.wrapping_sub(rhs.magic),
+        }
+    }
+
+    /// Restrict every member to the matching members of `min` and `max`.
+    ///
+    /// # Panics
+    /// Panics if any member of `min` is greater than the same member of `max`.
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stat = Stat {health: 150, attack: -5, defense: 10, magic: 10};
+    /// let min = Stat {health: 0, attack: 0, defense: 0, magic: 0};
+    /// let max = Stat {health: 100, attack: 100, defense: 100, magic: 100};
+    /// assert_eq!(stat.clamp(min, max), Stat {health: 100, attack: 0, defense: 10, magic: 10});
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self {
+            health: self.health.clamp(min.health, max.health),
+            attack: self.attack.clamp(min.attack, max.attack),
+            defense: self.defense.clamp(min.defense, max.defense),
+            magic: self.magic.clamp(min.magic, max.magic),
+        }
+    }
+}
+
+/// Error returned when a `Stat` operation can't produce a value.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StatError {
+    Overflow,
+}
+
+impl fmt::Display for StatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StatError::Overflow => write!(f, "stat arithmetic overflowed"),
+        }
+    }
+}
+
+impl Error for StatError {}
+
+/// How `Stat` arithmetic behaves when a member leaves the `i32` range.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum OverflowPolicy {
+    /// Stop at `i32::MIN` / `i32::MAX`.
+    #[default]
+    Saturating,
+    /// Wrap around like release-mode integer arithmetic.
+    Wrapping,
+    /// Fail with `StatError::Overflow`.
+    Checked,
+    /// Saturate, then clamp every member between `min` and `max`.
+    Clamped { min: Stat, max: Stat },
+}
+
+impl OverflowPolicy {
+    /// Add two `Stat` following this policy.
+    /// # Examples
+    /// ```
+    /// use game::{OverflowPolicy, Stat, StatError};
+    /// let stat = Stat {health: i32::MAX, attack: 0, defense: 0, magic: 0};
+    /// let stat2 = Stat {health: 1, attack: 0, defense: 0, magic: 0};
+    /// assert_eq!(OverflowPolicy::Checked.add(stat, stat2), Err(StatError::Overflow));
+    /// assert_eq!(OverflowPolicy::Saturating.add(stat, stat2), Ok(stat));
+    /// ```
+    pub fn add(self, lhs: Stat, rhs: Stat) -> Result<Stat, StatError> {
+        match self {
+            OverflowPolicy::Saturating => Ok(lhs.saturating_add(rhs)),
+            OverflowPolicy::Wrapping => Ok(lhs.wrapping_add(rhs)),
+            OverflowPolicy::Checked => lhs.checked_add(rhs).ok_or(StatError::Overflow),
+            OverflowPolicy::Clamped { min, max } => Ok(lhs.saturating_add(rhs).clamp(min, max)),
+        }
+    }
+
+    /// Subtract two `Stat` following this policy.
+    pub fn sub(self, lhs: Stat, rhs: Stat) -> Result<Stat, StatError> {
+        match self {
+            OverflowPolicy::Saturating => Ok(lhs.saturating_sub(rhs)),
+            OverflowPolicy::Wrapping => Ok(lhs.wrapping_sub(rhs)),
+            OverflowPolicy::Checked => lhs.checked_sub(rhs).ok_or(StatError::Overflow),
+            OverflowPolicy::Clamped { min, max } => Ok(lhs.saturating_sub(rhs).clamp(min, max)),
+        }
+    }
 }
 
 impl fmt::Display for Stat {
@@ -32,9 +300,11 @@ impl Add for Stat {
     type Output = Self;
 
     /// Add two stat member to a new `Stat`
+    ///
+    /// Overflow follows plain `i32` arithmetic, see `OverflowPolicy` for the safe variants.
     /// # Examples
     /// ```
-    /// use game::Stat
+    /// use game::Stat;
     /// let stat = Stat {health: 10, attack: 10, defense: 10, magic: 10};
     /// let stat2 = Stat {health: 10, attack: 10, defense: 10, magic: 10};
     /// let stat3 = stat + stat2;
@@ -51,15 +321,15 @@ impl Add for Stat {
 }
 
 impl AddAssign for Stat {
-
     /// Add and assign back to the original `Stat`
     /// # Examples
     /// ```
-    /// use game::Stat
+    /// use game::Stat;
     /// let mut stat = Stat {health: 10, attack: 10, defense: 10, magic: 10};
     /// let stat2 = Stat {health: 10, attack: 10, defense: 10, magic: 10};
     /// stat += stat2;
     /// assert_eq!(stat, Stat {health: 20, attack: 20, defense: 20, magic: 20});
+    /// ```
     fn add_assign(&mut self, rhs: Self) {
         self.health += rhs.health;
         self.attack += rhs.attack;
@@ -72,13 +342,16 @@ impl Sub for Stat {
     type Output = Self;
 
     /// Subtract two stat member to a new `Stat`
+    ///
+    /// Overflow follows plain `i32` arithmetic, see `OverflowPolicy` for the safe variants.
     /// # Examples
     /// ```
-    /// use game::Stat
+    /// use game::Stat;
     /// let stat = Stat {health: 10, attack: 10, defense: 10, magic: 10};
     /// let stat2 = Stat {health: 10, attack: 10, defense: 10, magic: 10};
     /// let stat3 = stat - stat2;
     /// assert_eq!(stat3, Stat {health: 0, attack: 0, defense: 0, magic: 0});
+    /// ```
     fn sub(self, rhs: Self) -> Self::Output {
         Self {
             health: self.health - rhs.health,
@@ -90,15 +363,15 @@ impl Sub for Stat {
 }
 
 impl SubAssign for Stat {
-
     /// Subtract and assign back to the original `Stat`
     /// # Examples
     /// ```
-    /// use game::Stat
+    /// use game::Stat;
     /// let mut stat = Stat {health: 10, attack: 10, defense: 10, magic: 10};
     /// let stat2 = Stat {health: 10, attack: 10, defense: 10, magic: 10};
     /// stat -= stat2;
     /// assert_eq!(stat, Stat {health: 0, attack: 0, defense: 0, magic: 0});
+    /// ```
     fn sub_assign(&mut self, rhs: Self) {
         self.health -= rhs.health;
         self.attack -= rhs.attack;
@@ -107,6 +380,157 @@ impl SubAssign for Stat {
     }
 }
 
+impl Mul<i32> for Stat {
+    type Output = Self;
+
+    /// Scale every stat member by an integer factor, saturating at the `i32` bounds
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stat = Stat {health: 10, attack: 10, defense: 10, magic: 10};
+    /// assert_eq!(stat * 3, Stat {health: 30, attack: 30, defense: 30, magic: 30});
+    /// ```
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.saturating_mul(rhs)
+    }
+}
+
+impl Mul<f32> for Stat {
+    type Output = Self;
+
+    /// Scale every stat member by a float factor
+    ///
+    /// Each result is rounded to the nearest integer, with halfway cases rounded
+    /// away from zero, and saturates at the `i32` bounds.
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stat = Stat {health: 10, attack: 15, defense: -15, magic: 0};
+    /// assert_eq!(stat * 1.5, Stat {health: 15, attack: 23, defense: -23, magic: 0});
+    /// ```
+    fn mul(self, rhs: f32) -> Self::Output {
+        let scale = |value: i32| (value as f32 * rhs).round() as i32;
+        Self {
+            health: scale(self.health),
+            attack: scale(self.attack),
+            defense: scale(self.defense),
+            magic: scale(self.magic),
+        }
+    }
+}
+
+impl Mul for Stat {
+    type Output = Self;
+
+    /// Multiply two stat member by member, saturating at the `i32` bounds
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stat = Stat {health: 10, attack: 10, defense: 10, magic: 10};
+    /// let stat2 = Stat {health: 1, attack: 2, defense: 3, magic: 0};
+    /// assert_eq!(stat * stat2, Stat {health: 10, attack: 20, defense: 30, magic: 0});
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            health: self.health.saturating_mul(rhs.health),
+            attack: self.attack.saturating_mul(rhs.attack),
+            defense: self.defense.saturating_mul(rhs.defense),
+            magic: self.magic.saturating_mul(rhs.magic),
+        }
+    }
+}
+
+impl Div<i32> for Stat {
+    type Output = Self;
+
+    /// Divide every stat member by an integer, truncating toward zero
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stat = Stat {health: 10, attack: 15, defense: -15, magic: 0};
+    /// assert_eq!(stat / 2, Stat {health: 5, attack: 7, defense: -7, magic: 0});
+    /// ```
+    fn div(self, rhs: i32) -> Self::Output {
+        Self {
+            health: self.health / rhs,
+            attack: self.attack / rhs,
+            defense: self.defense / rhs,
+            magic: self.magic / rhs,
+        }
+    }
+}
+
+impl Div for Stat {
+    type Output = Self;
+
+    /// Divide two stat member by member, truncating toward zero
+    ///
+    /// # Panics
+    /// Panics if any member of `rhs` is zero.
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stat = Stat {health: 10, attack: 10, defense: 10, magic: 10};
+    /// let stat2 = Stat {health: 1, attack: 2, defense: 3, magic: 4};
+    /// assert_eq!(stat / stat2, Stat {health: 10, attack: 5, defense: 3, magic: 2});
+    /// ```
+    fn div(self, rhs: Self) -> Self::Output {
+        Self {
+            health: self.health / rhs.health,
+            attack: self.attack / rhs.attack,
+            defense: self.defense / rhs.defense,
+            magic: self.magic / rhs.magic,
+        }
+    }
+}
+
+impl Neg for Stat {
+    type Output = Self;
+
+    /// Negate every stat member, `i32::MIN` saturates to `i32::MAX`
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stat = Stat {health: 10, attack: -10, defense: 0, magic: 10};
+    /// assert_eq!(-stat, Stat {health: -10, attack: 10, defense: 0, magic: -10});
+    /// ```
+    fn neg(self) -> Self::Output {
+        Self {
+            health: self.health.saturating_neg(),
+            attack: self.attack.saturating_neg(),
+            defense: self.defense.saturating_neg(),
+            magic: self.magic.saturating_neg(),
+        }
+    }
+}
+
+impl Sum for Stat {
+    /// Add up every `Stat` of an iterator, saturating at the `i32` bounds, an
+    /// empty iterator sums to zero
+    /// # Examples
+    /// ```
+    /// use game::Stat;
+    /// let stats = vec![
+    ///     Stat {health: 10, attack: 0, defense: 0, magic: 0},
+    ///     Stat {health: 0, attack: 10, defense: 0, magic: 0},
+    /// ];
+    /// let total: Stat = stats.into_iter().sum();
+    /// assert_eq!(total, Stat {health: 10, attack: 10, defense: 0, magic: 0});
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Self::saturating_add)
+    }
+}
+
+impl<'a> Sum<&'a Stat> for Stat {
+    fn sum<I: Iterator<Item = &'a Stat>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,15 +538,18 @@ mod tests {
     use parameterized::parameterized as pm;
 
     #[test]
-    fn test_stat_display(){
+    fn test_stat_display() {
         let stat = Stat {
             health: 100,
             attack: 100,
             magic: 100,
-            defense: 100
+            defense: 100,
         };
 
-        assert_eq!(format!("{}", stat), "State: [health: 100, attack: 100, defense: 100, magic: 100]\n");
+        assert_eq!(
+            format!("{}", stat),
+            "State: [health: 100, attack: 100, defense: 100, magic: 100]\n"
+        );
     }
 
     mod stat_tests {
@@ -184,11 +611,19 @@ mod tests {
             };
 
             stat += stat2;
-            assert_eq!(stat, Stat{health: 0, attack: 0, defense: 0, magic: 0})
+            assert_eq!(
+                stat,
+                Stat {
+                    health: 0,
+                    attack: 0,
+                    defense: 0,
+                    magic: 0
+                }
+            )
         }
 
         #[test]
-        fn test_stat_sub_assign(){
+        fn test_stat_sub_assign() {
             let mut stat = Stat {
                 health: 10,
                 attack: 10,
@@ -202,7 +637,462 @@ mod tests {
                 magic: 10,
             };
             stat -= stat2;
-            assert_eq!(stat, Stat{health: 0, attack: 0, defense: 0, magic: 0})
+            assert_eq!(
+                stat,
+                Stat {
+                    health: 0,
+                    attack: 0,
+                    defense: 0,
+                    magic: 0
+                }
+            )
+        }
+
+        #[pm(stat = {
+            Stat {health: 10, attack: 10, defense: 10, magic: 10},
+            Stat {health: i32::MAX, attack: 0, defense: 0, magic: 0},
+            Stat {health: 0, attack: 0, defense: 0, magic: i32::MIN},
+        }, expect = {
+            Some(Stat {health: 11, attack: 11, defense: 11, magic: 11}),
+            None,
+            Some(Stat {health: 1, attack: 1, defense: 1, magic: i32::MIN + 1}),
+        })]
+        fn test_stat_checked_add(stat: Stat, expect: Option<Stat>) {
+            let stat2 = Stat {
+                health: 1,
+                attack: 1,
+                defense: 1,
+                magic: 1,
+            };
+            assert_eq!(stat.checked_add(stat2), expect)
+        }
+
+        #[pm(stat = {
+            Stat {health: 10, attack: 10, defense: 10, magic: 10},
+            Stat {health: i32::MIN, attack: 0, defense: 0, magic: 0},
+        }, expect = {
+            Some(Stat {health: 9, attack: 9, defense: 9, magic: 9}),
+            None,
+        })]
+        fn test_stat_checked_sub(stat: Stat, expect: Option<Stat>) {
+            let stat2 = Stat {
+                health: 1,
+                attack: 1,
+                defense: 1,
+                magic: 1,
+            };
+            assert_eq!(stat.checked_sub(stat2), expect)
+        }
+
+        #[test]
+        fn test_stat_saturating_add() {
+            let stat = Stat {
+                health: i32::MAX,
+                attack: i32::MAX - 1,
+                defense: 0,
+                magic: 0,
+            };
+            let stat2 = Stat {
+                health: 10,
+                attack: 10,
+                defense: 10,
+                magic: -10,
+            };
+            assert_eq!(
+                stat.saturating_add(stat2),
+                Stat {
+                    health: i32::MAX,
+                    attack: i32::MAX,
+                    defense: 10,
+                    magic: -10
+                }
+            )
+        }
+
+        #[test]
+        fn test_stat_saturating_sub() {
+            let stat = Stat {
+                health: i32::MIN,
+                attack: 0,
+                defense: 0,
+                magic: i32::MAX,
+            };
+            let stat2 = Stat {
+                health: 10,
+                attack: 10,
+                defense: -10,
+                magic: -10,
+            };
+            assert_eq!(
+                stat.saturating_sub(stat2),
+                Stat {
+                    health: i32::MIN,
+                    attack: -10,
+                    defense: 10,
+                    magic: i32::MAX
+                }
+            )
+        }
+
+        #[test]
+        fn test_stat_clamp() {
+            let stat = Stat {
+                health: 200,
+                attack: -20,
+                defense: 50,
+                magic: 100,
+            };
+            let min = Stat {
+                health: 0,
+                attack: 0,
+                defense: 0,
+                magic: 0,
+            };
+            let max = Stat {
+                health: 100,
+                attack: 100,
+                defense: 100,
+                magic: 100,
+            };
+            assert_eq!(
+                stat.clamp(min, max),
+                Stat {
+                    health: 100,
+                    attack: 0,
+                    defense: 50,
+                    magic: 100
+                }
+            )
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_stat_clamp_invalid_bounds() {
+            let stat = Stat {
+                health: 0,
+                attack: 0,
+                defense: 0,
+                magic: 0,
+            };
+            let min = Stat {
+                health: 10,
+                attack: 0,
+                defense: 0,
+                magic: 0,
+            };
+            let max = Stat {
+                health: 0,
+                attack: 0,
+                defense: 0,
+                magic: 0,
+            };
+            stat.clamp(min, max);
+        }
+
+        #[pm(policy = {
+            OverflowPolicy::Saturating,
+            OverflowPolicy::Wrapping,
+            OverflowPolicy::Checked,
+            OverflowPolicy::Clamped {
+                min: Stat {health: 0, attack: 0, defense: 0, magic: 0},
+                max: Stat {health: 100, attack: 100, defense: 100, magic: 100},
+            },
+        }, expect = {
+            Ok(Stat {health: i32::MAX, attack: 20, defense: 0, magic: 0}),
+            Ok(Stat {health: i32::MIN, attack: 20, defense: 0, magic: 0}),
+            Err(StatError::Overflow),
+            Ok(Stat {health: 100, attack: 20, defense: 0, magic: 0}),
+        })]
+        fn test_overflow_policy_add(policy: OverflowPolicy, expect: Result<Stat, StatError>) {
+            let stat = Stat {
+                health: i32::MAX,
+                attack: 10,
+                defense: 0,
+                magic: 0,
+            };
+            let stat2 = Stat {
+                health: 1,
+                attack: 10,
+                defense: 0,
+                magic: 0,
+            };
+            assert_eq!(policy.add(stat, stat2), expect)
+        }
+
+        #[pm(policy = {
+            OverflowPolicy::Saturating,
+            OverflowPolicy::Wrapping,
+            OverflowPolicy::Checked,
+        }, expect = {
+            Ok(Stat {health: i32::MIN, attack: 0, defense: 0, magic: 0}),
+            Ok(Stat {health: i32::MAX, attack: 0, defense: 0, magic: 0}),
+            Err(StatError::Overflow),
+        })]
+        fn test_overflow_policy_sub(policy: OverflowPolicy, expect: Result<Stat, StatError>) {
+            let stat = Stat {
+                health: i32::MIN,
+                attack: 10,
+                defense: 0,
+                magic: 0,
+            };
+            let stat2 = Stat {
+                health: 1,
+                attack: 10,
+                defense: 0,
+                magic: 0,
+            };
+            assert_eq!(policy.sub(stat, stat2), expect)
+        }
+
+        #[pm(factor = {0, 2, -1}, expect = {
+            Stat {health: 0, attack: 0, defense: 0, magic: 0},
+            Stat {health: 20, attack: -20, defense: 0, magic: 38},
+            Stat {health: -10, attack: 10, defense: 0, magic: -19},
+        })]
+        fn test_stat_mul_i32(factor: i32, expect: Stat) {
+            let stat = Stat {
+                health: 10,
+                attack: -10,
+                defense: 0,
+                magic: 19,
+            };
+            assert_eq!(stat * factor, expect)
+        }
+
+        #[pm(factor = {1.5, 0.5, -0.5, 0.0}, expect = {
+            Stat {health: 15, attack: -15, defense: 2, magic: 29},
+            Stat {health: 5, attack: -5, defense: 1, magic: 10},
+            Stat {health: -5, attack: 5, defense: -1, magic: -10},
+            Stat {health: 0, attack: 0, defense: 0, magic: 0},
+        })]
+        fn test_stat_mul_f32(factor: f32, expect: Stat) {
+            let stat = Stat {
+                health: 10,
+                attack: -10,
+                defense: 1,
+                magic: 19,
+            };
+            assert_eq!(stat * factor, expect)
+        }
+
+        #[test]
+        fn test_stat_mul_f32_saturates() {
+            let stat = Stat {
+                health: i32::MAX,
+                attack: i32::MIN,
+                defense: 0,
+                magic: 0,
+            };
+            assert_eq!(
+                stat * 2.0,
+                Stat {
+                    health: i32::MAX,
+                    attack: i32::MIN,
+                    defense: 0,
+                    magic: 0
+                }
+            )
+        }
+
+        #[test]
+        fn test_stat_mul_stat() {
+            let stat = Stat {
+                health: 10,
+                attack: -10,
+                defense: 0,
+                magic: 19,
+            };
+            let stat2 = Stat {
+                health: 2,
+                attack: 3,
+                defense: 100,
+                magic: -1,
+            };
+            assert_eq!(
+                stat * stat2,
+                Stat {
+                    health: 20,
+                    attack: -30,
+                    defense: 0,
+                    magic: -19
+                }
+            )
+        }
+
+        #[pm(divisor = {1, 3, -2}, expect = {
+            Stat {health: 10, attack: -10, defense: 0, magic: 19},
+            Stat {health: 3, attack: -3, defense: 0, magic: 6},
+            Stat {health: -5, attack: 5, defense: 0, magic: -9},
+        })]
+        fn test_stat_div_i32(divisor: i32, expect: Stat) {
+            let stat = Stat {
+                health: 10,
+                attack: -10,
+                defense: 0,
+                magic: 19,
+            };
+            assert_eq!(stat / divisor, expect)
+        }
+
+        #[test]
+        fn test_stat_div_stat() {
+            let stat = Stat {
+                health: 10,
+                attack: -10,
+                defense: 0,
+                magic: 19,
+            };
+            let stat2 = Stat {
+                health: 2,
+                attack: 3,
+                defense: 100,
+                magic: -1,
+            };
+            assert_eq!(
+                stat / stat2,
+                Stat {
+                    health: 5,
+                    attack: -3,
+                    defense: 0,
+                    magic: -19
+                }
+            )
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_stat_div_by_zero() {
+            let stat = Stat {
+                health: 10,
+                attack: 10,
+                defense: 10,
+                magic: 10,
+            };
+            let _ = stat / 0;
+        }
+
+        #[test]
+        fn test_stat_neg() {
+            let stat = Stat {
+                health: 10,
+                attack: -10,
+                defense: 0,
+                magic: 19,
+            };
+            assert_eq!(
+                -stat,
+                Stat {
+                    health: -10,
+                    attack: 10,
+                    defense: 0,
+                    magic: -19
+                }
+            )
+        }
+
+        #[test]
+        fn test_stat_sum() {
+            let stats = [
+                Stat {
+                    health: 10,
+                    attack: 0,
+                    defense: 5,
+                    magic: 0,
+                },
+                Stat {
+                    health: 0,
+                    attack: 15,
+                    defense: 4,
+                    magic: 0,
+                },
+                Stat {
+                    health: 0,
+                    attack: 0,
+                    defense: 10,
+                    magic: 70,
+                },
+            ];
+            let expect = Stat {
+                health: 10,
+                attack: 15,
+                defense: 19,
+                magic: 70,
+            };
+            assert_eq!(stats.iter().sum::<Stat>(), expect);
+            assert_eq!(stats.into_iter().sum::<Stat>(), expect);
+        }
+
+        #[test]
+        fn test_stat_arithmetic_saturates() {
+            let stat = Stat {
+                health: i32::MAX,
+                attack: i32::MIN,
+                defense: 1,
+                magic: 0,
+            };
+            let max = Stat {
+                health: i32::MAX,
+                attack: i32::MAX,
+                defense: 1,
+                magic: 0,
+            };
+            assert_eq!(
+                stat * 2,
+                Stat {
+                    health: i32::MAX,
+                    attack: i32::MIN,
+                    defense: 2,
+                    magic: 0
+                }
+            );
+            assert_eq!(
+                stat * max,
+                Stat {
+                    health: i32::MAX,
+                    attack: i32::MIN,
+                    defense: 1,
+                    magic: 0
+                }
+            );
+            assert_eq!(
+                -stat,
+                Stat {
+                    health: -i32::MAX,
+                    attack: i32::MAX,
+                    defense: -1,
+                    magic: 0
+                }
+            );
+            assert_eq!([stat, stat].iter().sum::<Stat>(), stat * 2);
+        }
+
+        #[test]
+        fn test_stat_get_set() {
+            let mut stat = Stat {
+                health: 0,
+                attack: 0,
+                defense: 0,
+                magic: 0,
+            };
+            for (value, field) in StatField::ALL.into_iter().enumerate() {
+                stat.set(field, value as i32 + 1);
+            }
+            assert_eq!(
+                stat,
+                Stat {
+                    health: 1,
+                    attack: 2,
+                    defense: 3,
+                    magic: 4
+                }
+            );
+            assert_eq!(stat.get(StatField::Magic), 4);
+        }
+
+        #[test]
+        fn test_stat_sum_empty() {
+            let stats: Vec<Stat> = Vec::new();
+            assert_eq!(stats.into_iter().sum::<Stat>(), Stat::default())
         }
     }
 }
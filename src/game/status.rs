@@ -0,0 +1,492 @@
+use std::borrow::Cow;
+
+use crate::game::stat::{Stat, StatField};
+use crate::game::vitals::Vitals;
+
+/// What happens when an effect is applied while already active.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Stacking {
+    /// Reset the duration.
+    Refresh,
+    /// Add a stack, up to `max`, and reset the duration.
+    Intensify { max: u32 },
+    /// Keep the active effect as is.
+    Ignore,
+}
+
+/// An effect lasting a number of turns, e.g. poison or stun.
+///
+/// Effects are told apart by name, applying an effect with the same name as
+/// an active one follows the `stacking` rule. A tick is one turn of the
+/// holder: `Battle` ticks each side at the start of its turn, outside a fight
+/// the caller has to call `Character::tick_statuses` itself.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StatusEffect {
+    pub name: Cow<'static, str>,
+    /// Number of ticks the effect lasts.
+    pub duration: u32,
+    pub stacking: Stacking,
+    /// Change to current health and mana on every tick, per stack.
+    pub per_tick: Vitals,
+    /// Added to the holder's stat while active, per stack.
+    pub stat: Stat,
+    /// Whether the holder skips its turns while the effect is active.
+    pub stun: bool,
+}
+
+impl StatusEffect {
+    fn new(name: impl Into<Cow<'static, str>>, duration: u32, stacking: Stacking) -> Self {
+        Self {
+            name: name.into(),
+            duration,
+            stacking,
+            per_tick: Vitals { health: 0, mana: 0 },
+            stat: Stat::default(),
+            stun: false,
+        }
+    }
+
+    /// Lose `damage` health every tick, stacks up to five times. Negative
+    /// damage counts as zero.
+    pub fn poison(damage: i32, duration: u32) -> Self {
+        Self {
+            per_tick: Vitals {
+                health: -damage.max(0),
+                mana: 0,
+            },
+            ..Self::new("Poison", duration, Stacking::Intensify { max: 5 })
+        }
+    }
+
+    /// Lose `damage` health every tick, reapplying refreshes the duration.
+    /// Negative damage counts as zero.
+    pub fn burn(damage: i32, duration: u32) -> Self {
+        Self {
+            per_tick: Vitals {
+                health: -damage.max(0),
+                mana: 0,
+            },
+            ..Self::new("Burn", duration, Stacking::Refresh)
+        }
+    }
+
+    /// Restore `amount` health every tick.
+    pub fn regen(amount: i32, duration: u32) -> Self {
+        Self {
+            per_tick: Vitals {
+                health: amount,
+                mana: 0,
+            },
+            ..Self::new("Regen", duration, Stacking::Refresh)
+        }
+    }
+
+    /// Raise defense by `defense` while active.
+    pub fn shield(defense: i32, duration: u32) -> Self {
+        Self {
+            stat: Stat {
+                health: 0,
+                attack: 0,
+                defense,
+                magic: 0,
+            },
+            ..Self::new("Shield", duration, Stacking::Refresh)
+        }
+    }
+
+    /// Temporary stat change, a debuff when any member of `delta` is negative.
+    /// # Examples
+    /// ```
+    /// use game::{Stat, StatusEffect};
+    /// let delta = Stat {health: 0, attack: -10, defense: 0, magic: 0};
+    /// let weaken = StatusEffect::stat_change("Weaken", delta, 2);
+    /// assert!(weaken.is_debuff());
+    /// assert!(!weaken.is_buff());
+    /// ```
+    pub fn stat_change(name: impl Into<Cow<'static, str>>, delta: Stat, duration: u32) -> Self {
+        Self {
+            stat: delta,
+            ..Self::new(name, duration, Stacking::Refresh)
+        }
+    }
+
+    /// Skip the next `duration` turns, can't be extended while active.
+    pub fn stun(duration: u32) -> Self {
+        Self {
+            stun: true,
+            ..Self::new("Stun", duration, Stacking::Ignore)
+        }
+    }
+
+    /// Raises the stat or restores vitals, without any harmful part.
+    pub fn is_buff(&self) -> bool {
+        let restores = self.per_tick.health > 0 || self.per_tick.mana > 0;
+        (restores || self.stat != Stat::default()) && !self.is_debuff()
+    }
+
+    /// Lowers a member of the stat, drains health or mana every tick, or stuns.
+    pub fn is_debuff(&self) -> bool {
+        self.stun
+            || self.per_tick.health < 0
+            || self.per_tick.mana < 0
+            || StatField::ALL.iter().any(|field| self.stat.get(*field) < 0)
+    }
+}
+
+/// A `StatusEffect` attached to a holder.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ActiveStatus {
+    effect: StatusEffect,
+    remaining: u32,
+    stacks: u32,
+}
+
+impl ActiveStatus {
+    pub fn effect(&self) -> &StatusEffect {
+        &self.effect
+    }
+
+    /// Ticks left before the effect wears off.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    pub fn stacks(&self) -> u32 {
+        self.stacks
+    }
+
+    fn stack_factor(&self) -> i32 {
+        i32::try_from(self.stacks).unwrap_or(i32::MAX)
+    }
+}
+
+/// Outcome of `StatusEffects::tick`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StatusTick {
+    /// Sum of the per tick changes of all effects.
+    pub change: Vitals,
+    /// Names of the effects that wore off.
+    pub expired: Vec<Cow<'static, str>>,
+}
+
+/// Status effects held by one character, they only wear off through `tick`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusEffects {
+    active: Vec<ActiveStatus>,
+}
+
+impl StatusEffects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach an effect, returns whether anything changed.
+    /// # Examples
+    /// ```
+    /// use game::{StatusEffect, StatusEffects};
+    /// let mut statuses = StatusEffects::new();
+    /// assert!(statuses.apply(StatusEffect::poison(3, 2)));
+    /// assert!(statuses.apply(StatusEffect::poison(3, 2)));
+    /// assert_eq!(statuses.get("Poison").unwrap().stacks(), 2);
+    /// assert!(statuses.apply(StatusEffect::stun(1)));
+    /// assert!(!statuses.apply(StatusEffect::stun(1)));
+    /// ```
+    pub fn apply(&mut self, effect: StatusEffect) -> bool {
+        if effect.duration == 0 {
+            return false;
+        }
+        let Some(active) = self
+            .active
+            .iter_mut()
+            .find(|a| a.effect.name == effect.name)
+        else {
+            self.active.push(ActiveStatus {
+                remaining: effect.duration,
+                effect,
+                stacks: 1,
+            });
+            return true;
+        };
+        match effect.stacking {
+            Stacking::Ignore => return false,
+            Stacking::Refresh => {}
+            Stacking::Intensify { max } => active.stacks = (active.stacks + 1).min(max.max(1)),
+        }
+        active.remaining = effect.duration;
+        active.effect = effect;
+        true
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ActiveStatus> {
+        self.active.iter().find(|active| active.effect.name == name)
+    }
+
+    /// Take off the effect called `name`.
+    pub fn remove(&mut self, name: &str) -> Option<ActiveStatus> {
+        let index = self
+            .active
+            .iter()
+            .position(|active| active.effect.name == name)?;
+        Some(self.active.remove(index))
+    }
+
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// Active effects in the order they were first applied.
+    pub fn iter(&self) -> impl Iterator<Item = &ActiveStatus> {
+        self.active.iter()
+    }
+
+    /// Active effects raising the stat, see `StatusEffect::is_buff`.
+    pub fn buffs(&self) -> impl Iterator<Item = &ActiveStatus> {
+        self.active.iter().filter(|active| active.effect.is_buff())
+    }
+
+    /// Active effects lowering the stat, see `StatusEffect::is_debuff`.
+    pub fn debuffs(&self) -> impl Iterator<Item = &ActiveStatus> {
+        self.active
+            .iter()
+            .filter(|active| active.effect.is_debuff())
+    }
+
+    /// Remove every buff, returning their names.
+    pub fn dispel_buffs(&mut self) -> Vec<Cow<'static, str>> {
+        self.dispel(StatusEffect::is_buff)
+    }
+
+    /// Remove every debuff, returning their names.
+    pub fn dispel_debuffs(&mut self) -> Vec<Cow<'static, str>> {
+        self.dispel(StatusEffect::is_debuff)
+    }
+
+    fn dispel(&mut self, matches: fn(&StatusEffect) -> bool) -> Vec<Cow<'static, str>> {
+        let removed = self
+            .active
+            .iter()
+            .filter(|active| matches(&active.effect))
+            .map(|active| active.effect.name.clone())
+            .collect();
+        self.active.retain(|active| !matches(&active.effect));
+        removed
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        self.active.iter().any(|active| active.effect.stun)
+    }
+
+    /// Sum of the stat changes of the active effects.
+    pub fn stat_delta(&self) -> Stat {
+        self.active
+            .iter()
+            .map(|active| active.effect.stat.saturating_mul(active.stack_factor()))
+            .fold(Stat::default(), Stat::saturating_add)
+    }
+
+    /// Advance every effect by one tick and drop the ones that wore off.
+    ///
+    /// The returned change is not applied, see `Character::tick_statuses`.
+    pub fn tick(&mut self) -> StatusTick {
+        let mut change = Vitals { health: 0, mana: 0 };
+        for active in &mut self.active {
+            let stacks = active.stack_factor();
+            let per_tick = active.effect.per_tick;
+            change.health = change
+                .health
+                .saturating_add(per_tick.health.saturating_mul(stacks));
+            change.mana = change
+                .mana
+                .saturating_add(per_tick.mana.saturating_mul(stacks));
+            active.remaining -= 1;
+        }
+        let expired = self
+            .active
+            .iter()
+            .filter(|active| active.remaining == 0)
+            .map(|active| active.effect.name.clone())
+            .collect();
+        self.active.retain(|active| active.remaining > 0);
+        StatusTick { change, expired }
+    }
+}
+
+#[cfg(test)]
+mod status_tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_new_effect() {
+        let mut statuses = StatusEffects::new();
+        assert!(statuses.apply(StatusEffect::burn(4, 3)));
+        let burn = statuses.get("Burn").unwrap();
+        assert_eq!(burn.remaining(), 3);
+        assert_eq!(burn.stacks(), 1);
+        assert_eq!(statuses.len(), 1);
+    }
+
+    #[test]
+    fn test_apply_zero_duration_is_ignored() {
+        let mut statuses = StatusEffects::new();
+        assert!(!statuses.apply(StatusEffect::burn(4, 0)));
+        assert!(statuses.is_empty());
+    }
+
+    #[test]
+    fn test_refresh_resets_duration() {
+        let mut statuses = StatusEffects::new();
+        statuses.apply(StatusEffect::burn(4, 3));
+        statuses.tick();
+        statuses.apply(StatusEffect::burn(6, 3));
+        let burn = statuses.get("Burn").unwrap();
+        assert_eq!(burn.remaining(), 3);
+        assert_eq!(burn.stacks(), 1);
+        assert_eq!(burn.effect().per_tick.health, -6);
+    }
+
+    #[test]
+    fn test_intensify_caps_stacks() {
+        let mut statuses = StatusEffects::new();
+        for _ in 0..7 {
+            statuses.apply(StatusEffect::poison(2, 3));
+        }
+        assert_eq!(statuses.get("Poison").unwrap().stacks(), 5);
+        assert_eq!(
+            statuses.tick().change,
+            Vitals {
+                health: -10,
+                mana: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_ignore_keeps_active_effect() {
+        let mut statuses = StatusEffects::new();
+        statuses.apply(StatusEffect::stun(2));
+        statuses.tick();
+        assert!(!statuses.apply(StatusEffect::stun(2)));
+        assert_eq!(statuses.get("Stun").unwrap().remaining(), 1);
+    }
+
+    #[test]
+    fn test_tick_sums_changes_and_expires() {
+        let mut statuses = StatusEffects::new();
+        statuses.apply(StatusEffect::poison(3, 1));
+        statuses.apply(StatusEffect::regen(5, 2));
+        let tick = statuses.tick();
+        assert_eq!(tick.change, Vitals { health: 2, mana: 0 });
+        assert_eq!(tick.expired, vec!["Poison"]);
+        let tick = statuses.tick();
+        assert_eq!(tick.change, Vitals { health: 5, mana: 0 });
+        assert_eq!(tick.expired, vec!["Regen"]);
+        assert!(statuses.is_empty());
+    }
+
+    #[test]
+    fn test_stun_and_stat_delta() {
+        let mut statuses = StatusEffects::new();
+        assert!(!statuses.is_stunned());
+        statuses.apply(StatusEffect::stun(1));
+        statuses.apply(StatusEffect::shield(15, 2));
+        assert!(statuses.is_stunned());
+        assert_eq!(
+            statuses.stat_delta(),
+            Stat {
+                health: 0,
+                attack: 0,
+                defense: 15,
+                magic: 0
+            }
+        );
+        statuses.tick();
+        assert!(!statuses.is_stunned());
+    }
+
+    fn attack(attack: i32) -> Stat {
+        Stat {
+            health: 0,
+            attack,
+            defense: 0,
+            magic: 0,
+        }
+    }
+
+    #[test]
+    fn test_buff_and_debuff() {
+        assert!(StatusEffect::stat_change("Might", attack(10), 2).is_buff());
+        assert!(StatusEffect::stat_change("Weaken", attack(-10), 2).is_debuff());
+        assert!(StatusEffect::shield(10, 2).is_buff());
+        assert!(StatusEffect::regen(5, 2).is_buff());
+        let harmful = [
+            StatusEffect::poison(3, 2),
+            StatusEffect::burn(3, 2),
+            StatusEffect::stun(1),
+        ];
+        for harmful in harmful {
+            assert!(harmful.is_debuff());
+            assert!(!harmful.is_buff());
+        }
+    }
+
+    #[test]
+    fn test_buffs_revert_after_duration() {
+        let mut statuses = StatusEffects::new();
+        statuses.apply(StatusEffect::stat_change("Might", attack(20), 2));
+        assert_eq!(statuses.stat_delta(), attack(20));
+        statuses.tick();
+        assert_eq!(statuses.stat_delta(), attack(20));
+        statuses.tick();
+        assert_eq!(statuses.stat_delta(), Stat::default());
+    }
+
+    #[test]
+    fn test_dispel() {
+        let mut statuses = StatusEffects::new();
+        statuses.apply(StatusEffect::stat_change("Might", attack(20), 2));
+        statuses.apply(StatusEffect::stat_change("Weaken", attack(-5), 2));
+        statuses.apply(StatusEffect::poison(3, 2));
+        assert_eq!(statuses.buffs().count(), 1);
+        assert_eq!(statuses.debuffs().count(), 2);
+        assert_eq!(statuses.dispel_debuffs(), vec!["Weaken", "Poison"]);
+        assert_eq!(statuses.stat_delta(), attack(20));
+        assert_eq!(statuses.dispel_buffs(), vec!["Might"]);
+        assert!(statuses.is_empty());
+    }
+
+    #[test]
+    fn test_huge_effects_saturate() {
+        let mut statuses = StatusEffects::new();
+        statuses.apply(StatusEffect::poison(i32::MAX, 3));
+        statuses.apply(StatusEffect::poison(i32::MAX, 3));
+        assert_eq!(statuses.tick().change.health, i32::MIN);
+        statuses.apply(StatusEffect::stat_change("Might", attack(i32::MAX), 2));
+        statuses.apply(StatusEffect::stat_change("Giant", attack(i32::MAX), 2));
+        assert_eq!(statuses.stat_delta().attack, i32::MAX);
+    }
+
+    #[test]
+    fn test_negative_damage_does_not_heal() {
+        assert_eq!(StatusEffect::burn(i32::MIN, 1).per_tick.health, 0);
+        let poison = StatusEffect::poison(-5, 1);
+        assert_eq!(poison.per_tick.health, 0);
+        assert!(!poison.is_buff());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut statuses = StatusEffects::new();
+        statuses.apply(StatusEffect::poison(3, 4));
+        assert_eq!(statuses.remove("Poison").unwrap().remaining(), 4);
+        assert!(statuses.remove("Poison").is_none());
+        assert!(statuses.is_empty());
+    }
+}
@@ -0,0 +1,304 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::game::stat::{Interior, InteriorStatTrait, Stat, StatMutTrait, StatTrait};
+
+/// Current health and mana of an entity.
+///
+/// The maximums are not stored here, they come from the owner's `Stat`:
+/// `Stat::health` is the maximum health and `Stat::magic` the maximum mana.
+/// Raising those (e.g. by equipping an item) raises the caps, while healing
+/// only ever moves the current values up to them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Vitals {
+    pub health: i32,
+    pub mana: i32,
+}
+
+/// Read access to the `Vitals` of an entity.
+pub trait VitalsTrait: StatTrait {
+    fn get_vitals(&self) -> Vitals;
+}
+
+/// Write access to the `Stat` and `Vitals` of an entity.
+pub trait VitalsMutTrait: VitalsTrait + StatMutTrait {
+    fn set_vitals(&mut self, vitals: Vitals);
+}
+
+/// Write access through a shared reference, see `InteriorStatTrait`.
+pub trait InteriorVitalsTrait: VitalsTrait + InteriorStatTrait {
+    fn set_vitals(&self, vitals: Vitals);
+}
+
+impl<T: VitalsTrait + ?Sized> VitalsTrait for Interior<'_, T> {
+    fn get_vitals(&self) -> Vitals {
+        self.0.get_vitals()
+    }
+}
+
+impl<T: InteriorVitalsTrait + ?Sized> VitalsMutTrait for Interior<'_, T> {
+    fn set_vitals(&mut self, vitals: Vitals) {
+        InteriorVitalsTrait::set_vitals(self.0, vitals);
+    }
+}
+
+impl Vitals {
+    /// Fully restored vitals for the given `Stat`.
+    /// # Examples
+    /// ```
+    /// use game::{Stat, Vitals};
+    /// let stat = Stat {health: 90, attack: 40, defense: 55, magic: 10};
+    /// assert_eq!(Vitals::full(&stat), Vitals {health: 90, mana: 10});
+    /// ```
+    pub fn full(stat: &Stat) -> Self {
+        Self {
+            health: stat.health.max(0),
+            mana: stat.magic.max(0),
+        }
+    }
+
+    /// Keep the current values between zero and the maximums of `stat`.
+    pub fn clamp_to(self, stat: &Stat) -> Self {
+        let max = Self::full(stat);
+        Self {
+            health: self.health.clamp(0, max.health),
+            mana: self.mana.clamp(0, max.mana),
+        }
+    }
+
+    /// Restore health, capped at the maximum health of `stat`.
+    /// # Examples
+    /// ```
+    /// use game::{Stat, Vitals};
+    /// let stat = Stat {health: 90, attack: 40, defense: 55, magic: 0};
+    /// let vitals = Vitals {health: 80, mana: 0};
+    /// assert_eq!(vitals.heal(30, &stat), Vitals {health: 90, mana: 0});
+    /// ```
+    pub fn heal(self, amount: i32, stat: &Stat) -> Self {
+        Self {
+            health: self.health.saturating_add(amount.max(0)),
+            ..self
+        }
+        .clamp_to(stat)
+    }
+
+    /// Restore mana, capped at the maximum mana of `stat`.
+    pub fn restore_mana(self, amount: i32, stat: &Stat) -> Self {
+        Self {
+            mana: self.mana.saturating_add(amount.max(0)),
+            ..self
+        }
+        .clamp_to(stat)
+    }
+
+    /// Lose health, never going below zero.
+    pub fn damage(self, amount: i32) -> Self {
+        Self {
+            health: self.health.saturating_sub(amount.max(0)).max(0),
+            ..self
+        }
+    }
+
+    /// Spend mana, returning `None` if there is not enough of it.
+    pub fn spend_mana(self, amount: i32) -> Option<Self> {
+        let amount = amount.max(0);
+        if self.mana < amount {
+            return None;
+        }
+        Some(Self {
+            mana: self.mana - amount,
+            ..self
+        })
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.health > 0
+    }
+}
+
+impl fmt::Display for Vitals {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Vitals: [health: {health}, mana: {mana}]",
+            health = self.health,
+            mana = self.mana
+        )
+    }
+}
+
+#[cfg(test)]
+mod vitals_tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn stat() -> Stat {
+        Stat {
+            health: 100,
+            attack: 10,
+            defense: 10,
+            magic: 50,
+        }
+    }
+
+    #[test]
+    fn test_vitals_full() {
+        assert_eq!(
+            Vitals::full(&stat()),
+            Vitals {
+                health: 100,
+                mana: 50
+            }
+        );
+    }
+
+    #[test]
+    fn test_vitals_full_negative_stat() {
+        let stat = Stat {
+            health: -10,
+            attack: 0,
+            defense: 0,
+            magic: -10,
+        };
+        assert_eq!(Vitals::full(&stat), Vitals { health: 0, mana: 0 });
+    }
+
+    #[test]
+    fn test_vitals_heal_caps_at_max() {
+        let vitals = Vitals {
+            health: 90,
+            mana: 0,
+        };
+        assert_eq!(
+            vitals.heal(30, &stat()),
+            Vitals {
+                health: 100,
+                mana: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_vitals_heal_ignores_negative_amount() {
+        let vitals = Vitals {
+            health: 90,
+            mana: 0,
+        };
+        assert_eq!(vitals.heal(-30, &stat()), vitals);
+    }
+
+    #[test]
+    fn test_vitals_restore_mana_caps_at_max() {
+        let vitals = Vitals {
+            health: 10,
+            mana: 40,
+        };
+        assert_eq!(
+            vitals.restore_mana(30, &stat()),
+            Vitals {
+                health: 10,
+                mana: 50
+            }
+        );
+    }
+
+    #[test]
+    fn test_vitals_clamp_to_lowered_max() {
+        let vitals = Vitals {
+            health: 100,
+            mana: 50,
+        };
+        let lowered = Stat {
+            health: 60,
+            attack: 0,
+            defense: 0,
+            magic: 20,
+        };
+        assert_eq!(
+            vitals.clamp_to(&lowered),
+            Vitals {
+                health: 60,
+                mana: 20
+            }
+        );
+    }
+
+    #[test]
+    fn test_vitals_damage() {
+        let vitals = Vitals {
+            health: 20,
+            mana: 0,
+        };
+        assert_eq!(vitals.damage(15), Vitals { health: 5, mana: 0 });
+        assert_eq!(vitals.damage(50), Vitals { health: 0, mana: 0 });
+        assert!(!vitals.damage(50).is_alive());
+    }
+
+    #[test]
+    fn test_vitals_spend_mana() {
+        let vitals = Vitals {
+            health: 20,
+            mana: 30,
+        };
+        assert_eq!(
+            vitals.spend_mana(20),
+            Some(Vitals {
+                health: 20,
+                mana: 10
+            })
+        );
+        assert_eq!(vitals.spend_mana(40), None);
+    }
+
+    struct CellTarget {
+        stat: Cell<Stat>,
+        vitals: Cell<Vitals>,
+    }
+
+    impl StatTrait for CellTarget {
+        fn get_stat(&self) -> Stat {
+            self.stat.get()
+        }
+    }
+
+    impl InteriorStatTrait for CellTarget {
+        fn set_stat(&self, stat: Stat) {
+            self.stat.set(stat);
+        }
+    }
+
+    impl VitalsTrait for CellTarget {
+        fn get_vitals(&self) -> Vitals {
+            self.vitals.get()
+        }
+    }
+
+    impl InteriorVitalsTrait for CellTarget {
+        fn set_vitals(&self, vitals: Vitals) {
+            self.vitals.set(vitals);
+        }
+    }
+
+    #[test]
+    fn test_interior_adapter_writes_through_shared_reference() {
+        let target = CellTarget {
+            stat: Cell::new(stat()),
+            vitals: Cell::new(Vitals::full(&stat())),
+        };
+        let mut adapter = Interior(&target);
+        StatMutTrait::set_stat(&mut adapter, Stat::default());
+        VitalsMutTrait::set_vitals(&mut adapter, Vitals { health: 1, mana: 2 });
+        assert_eq!(adapter.get_stat(), Stat::default());
+        assert_eq!(target.get_stat(), Stat::default());
+        assert_eq!(target.get_vitals(), Vitals { health: 1, mana: 2 });
+    }
+
+    #[test]
+    fn test_vitals_display() {
+        let vitals = Vitals {
+            health: 20,
+            mana: 30,
+        };
+        assert_eq!(format!("{}", vitals), "Vitals: [health: 20, mana: 30]");
+    }
+}
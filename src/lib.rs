@@ -0,0 +1,75 @@
+pub mod game;
+
+pub use crate::game::affix::{Affix, AffixKind, AffixPool, Rarity, RolledAffix, RolledItem};
+pub use crate::game::battle::{Battle, BattleResult, Side, Turn};
+pub use crate::game::character::{Character, PromoteError, StatBreakdown};
+pub use crate::game::counter::{CounterError, CounterMatrix};
+pub use crate::game::crafting::{
+    CraftError, Crafted, CraftedConsumable, CraftedEquipment, Ingredient, MissingIngredient,
+    Recipe, RecipeBook, RecipeError, RecipeOutput,
+};
+pub use crate::game::equipment::Equipped;
+pub use crate::game::equipment_set::{EquipmentSet, SetBonus};
+pub use crate::game::inventory::{
+    AddError, ConsumableStack, Inventory, InventoryError, InventoryItem, UseOutcome,
+};
+pub use crate::game::item::{
+    BloodBag, ChestPlate, ConsumableTrait, EquipmentTrait, Helmet, ItemCategory, ItemError,
+    ItemTrait, Leggings, Ring, StrengthPotion, Sword, Wand,
+};
+pub use crate::game::loadout::{EquipmentSlot, Loadout, LoadoutError};
+pub use crate::game::loot::{ItemKind, Loot, LootEntry, LootTable};
+pub use crate::game::modifier::{apply_modifiers, ModifierKind, StatModifier};
+pub use crate::game::profession::{
+    Archer, Cleric, Knight, Paladin, ParseProfessionTypeError, Profession, ProfessionType, Rogue,
+    Sorcerer, Warrior,
+};
+pub use crate::game::progression::{Experience, GrowthCurve, LevelUp};
+pub use crate::game::promotion::{
+    Archmage, Berserker, Gladiator, Necromancer, Promotion, PromotionError, PromotionTree,
+};
+pub use crate::game::rng::{Rng, SeededRng};
+pub use crate::game::skill::{Cooldowns, Skill, SkillCost, SkillEffect, SkillTarget};
+pub use crate::game::stat::{
+    Interior, InteriorStatTrait, OverflowPolicy, Stat, StatError, StatField, StatMutTrait,
+    StatTrait,
+};
+pub use crate::game::status::{ActiveStatus, Stacking, StatusEffect, StatusEffects, StatusTick};
+pub use crate::game::vitals::{InteriorVitalsTrait, Vitals, VitalsMutTrait, VitalsTrait};
+
+/// Commonly used types and traits, meant to be glob imported.
+/// # Examples
+/// ```
+/// use game::prelude::*;
+/// let warrior = Warrior::new();
+/// assert_eq!(warrior.profession_type(), ProfessionType::WarriorType);
+/// ```
+pub mod prelude {
+    pub use crate::game::affix::{AffixPool, Rarity, RolledItem};
+    pub use crate::game::battle::{Battle, BattleResult, Side};
+    pub use crate::game::character::{Character, StatBreakdown};
+    pub use crate::game::counter::CounterMatrix;
+    pub use crate::game::crafting::{Crafted, Recipe, RecipeBook};
+    pub use crate::game::equipment::Equipped;
+    pub use crate::game::equipment_set::EquipmentSet;
+    pub use crate::game::inventory::{Inventory, InventoryItem, UseOutcome};
+    pub use crate::game::item::{
+        BloodBag, ChestPlate, ConsumableTrait, EquipmentTrait, Helmet, ItemCategory, ItemTrait,
+        Leggings, Ring, StrengthPotion, Sword, Wand,
+    };
+    pub use crate::game::loadout::{EquipmentSlot, Loadout};
+    pub use crate::game::loot::{ItemKind, LootEntry, LootTable};
+    pub use crate::game::modifier::{apply_modifiers, StatModifier};
+    pub use crate::game::profession::{
+        Archer, Cleric, Knight, Paladin, Profession, ProfessionType, Rogue, Sorcerer, Warrior,
+    };
+    pub use crate::game::progression::{Experience, GrowthCurve, LevelUp};
+    pub use crate::game::promotion::{
+        Archmage, Berserker, Gladiator, Necromancer, Promotion, PromotionTree,
+    };
+    pub use crate::game::rng::{Rng, SeededRng};
+    pub use crate::game::skill::{Skill, SkillCost, SkillEffect, SkillTarget};
+    pub use crate::game::stat::{OverflowPolicy, Stat, StatField, StatMutTrait, StatTrait};
+    pub use crate::game::status::{Stacking, StatusEffect, StatusEffects};
+    pub use crate::game::vitals::{Vitals, VitalsMutTrait, VitalsTrait};
+}